@@ -0,0 +1,127 @@
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use aoc_common::{for_each_group, groups, ParseError, Rng};
+
+pub mod report;
+
+pub struct Params {
+    pub top: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { top: 3 }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "top" => self.top = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Elf {
+    pub items: Vec<usize>,
+}
+
+impl Elf {
+    pub fn calorie_sum(&self) -> usize {
+        self.items.iter().sum()
+    }
+}
+
+pub fn parse_elves(input: &str) -> Result<Vec<Elf>, ParseError> {
+    return groups(input)
+        .map(|group| {
+            Ok(Elf {
+                items: group.parse_lines::<usize>()?,
+            })
+        })
+        .collect();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedElf {
+    // 1-based position in the input
+    pub index: usize,
+    pub calories: usize,
+    pub items: Vec<usize>,
+}
+
+// Single pass that keeps only the n best elves, in a min-heap whose root is the
+// next one to drop. Equal totals rank the earlier elf first.
+pub fn top_elves<R: BufRead>(reader: R, n: usize) -> Result<Vec<RankedElf>, ParseError> {
+    let mut heap = BinaryHeap::with_capacity(n);
+
+    for_each_group(reader, |group| {
+        let items = group.parse_lines::<usize>()?;
+        let elf = Reverse((items.iter().sum::<usize>(), Reverse(group.number), items));
+        if heap.len() < n {
+            heap.push(elf);
+        } else if let Some(mut worst) = heap.peek_mut() {
+            if elf < *worst {
+                *worst = elf;
+            }
+        }
+        return Ok(());
+    })?;
+
+    return Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(index), items))| RankedElf {
+            index,
+            calories,
+            items,
+        })
+        .collect());
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(top_elves(input.as_bytes(), 1)?
+        .first()
+        .map_or(0, |elf| elf.calories))
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize, ParseError> {
+    let top = top_elves(input.as_bytes(), params.top)?;
+    Ok(top.iter().map(|elf| elf.calories).sum())
+}
+
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(usize, usize), ParseError> {
+    solve_stream_with(reader, &Params::default())
+}
+
+// Both parts in one pass; only the current elf and the best ones are kept
+pub fn solve_stream_with<R: BufRead>(
+    reader: R,
+    params: &Params,
+) -> Result<(usize, usize), ParseError> {
+    let top = top_elves(reader, params.top.max(1))?;
+    return Ok((
+        top.first().map_or(0, |elf| elf.calories),
+        top.iter().take(params.top).map(|elf| elf.calories).sum(),
+    ));
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| rng.range(1000..=70000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+        + "\n"
+}
@@ -1,42 +1,77 @@
-use std::fs;
+use std::{env, fs, process};
 
-struct Elf {
-    items: Vec<usize>,
+use aoc_common::ParseError;
+use day_1::report::Report;
+
+const USAGE: &str = "Usage: day_1 [--input <path>] [--report text|csv] [--buckets <n>]";
+
+fn solve(input: &str) -> Result<(), ParseError> {
+    // Part 1
+    println!("Part 1: {}", day_1::part1(input)?);
+
+    // Part 2
+    println!("Part 2: {}", day_1::part2(input)?);
+
+    Ok(())
 }
 
-impl Elf {
-    pub fn calorie_sum(&self) -> usize {
-        self.items.iter().sum()
+fn report(input: &str, csv: bool, buckets: usize) -> Result<(), ParseError> {
+    let report = Report::new(&day_1::parse_elves(input)?, buckets);
+    if csv {
+        println!("{}", report.to_csv());
+    } else {
+        println!("{}", report.to_text());
     }
+    Ok(())
 }
 
-fn main() {
-    let mut elves: Vec<Elf> = vec![Elf { items: vec![] }];
-    let mut curr_elf: &mut Elf = elves.last_mut().unwrap();
-    let input: String = fs::read_to_string("input_p1").expect("Unable to read file");
-    let input_lines: Vec<&str> = input.lines().collect();
-
-    for line in input_lines {
-        if line.is_empty() {
-            elves.push(Elf { items: vec![] });
-            curr_elf = elves.last_mut().unwrap();
-            continue;
+// The input path, and None to solve both parts, otherwise (csv, buckets) for
+// the statistics report
+fn parse_args() -> Result<(String, Option<(bool, usize)>), String> {
+    let mut path = String::from("input_p1");
+    let mut format = None;
+    let mut buckets = 10;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => path = args.next().ok_or("Missing value for --input")?,
+            "--report" => {
+                format = match args.next().as_deref() {
+                    Some("text") => Some(false),
+                    Some("csv") => Some(true),
+                    _ => return Err(String::from("Expected text or csv after --report")),
+                }
+            }
+            "--buckets" => {
+                buckets = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("Expected a positive number after --buckets")?
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
         }
-        curr_elf.items.push(line.parse::<usize>().unwrap());
     }
+    Ok((path, format.map(|csv| (csv, buckets))))
+}
 
-    // Part 1
-    println!(
-        "Part 1: {}",
-        elves.iter().map(Elf::calorie_sum).max().unwrap()
-    );
-
-    // Part 2
-    let mut elves_sorted = elves.iter().map(Elf::calorie_sum).collect::<Vec<usize>>();
-    elves_sorted.sort();
+fn main() {
+    let (path, args) = parse_args().unwrap_or_else(|e| {
+        eprintln!("Error: {}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let input = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Error: Unable to read file {}: {}", path, e);
+        process::exit(1);
+    });
 
-    println!(
-        "Part 2: {:?}",
-        elves_sorted.iter().rev().take(3).sum::<usize>()
-    );
+    let result = match args {
+        Some((csv, buckets)) => report(&input, csv, buckets),
+        None => solve(&input),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e.render(&input));
+        process::exit(1);
+    }
 }
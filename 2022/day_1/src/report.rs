@@ -0,0 +1,190 @@
+use crate::Elf;
+
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub sum: usize,
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: f64,
+    // One value per entry of PERCENTILES
+    pub percentiles: Vec<f64>,
+}
+
+impl Summary {
+    // None when there are no values at all
+    pub fn of(values: &[usize]) -> Option<Summary> {
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        let sum = sorted.iter().sum::<usize>();
+
+        return Some(Summary {
+            count: sorted.len(),
+            sum,
+            min,
+            max,
+            mean: sum as f64 / sorted.len() as f64,
+            median: percentile(&sorted, 50),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| percentile(&sorted, p))
+                .collect(),
+        });
+    }
+}
+
+// Linear interpolation between the two closest ranks
+fn percentile(sorted: &[usize], p: u8) -> f64 {
+    let rank = (sorted.len() - 1) as f64 * p as f64 / 100.0;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    return sorted[low] as f64 + (sorted[high] as f64 - sorted[low] as f64) * (rank - low as f64);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: usize,
+    pub to: usize,
+    pub elves: usize,
+}
+
+// Equally wide buckets from the smallest to the largest total, at most `buckets` of them
+pub fn histogram(totals: &[usize], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return vec![];
+    };
+    let width = (max - min + 1).div_ceil(buckets.max(1));
+    let mut histogram = (0..(max - min + 1).div_ceil(width))
+        .map(|i| Bucket {
+            from: min + i * width,
+            to: min + (i + 1) * width - 1,
+            elves: 0,
+        })
+        .collect::<Vec<Bucket>>();
+    for total in totals {
+        histogram[(total - min) / width].elves += 1;
+    }
+    return histogram;
+}
+
+pub struct Report {
+    pub elves: Vec<Summary>,
+    pub items: Option<Summary>,
+    pub totals: Option<Summary>,
+    pub histogram: Vec<Bucket>,
+}
+
+impl Report {
+    // Parsed elves always carry at least one item, see `groups`
+    pub fn new(elves: &[Elf], buckets: usize) -> Self {
+        let items = elves
+            .iter()
+            .flat_map(|elf| elf.items.iter().copied())
+            .collect::<Vec<usize>>();
+        let totals = elves.iter().map(Elf::calorie_sum).collect::<Vec<usize>>();
+
+        return Self {
+            elves: elves
+                .iter()
+                .filter_map(|elf| Summary::of(&elf.items))
+                .collect(),
+            items: Summary::of(&items),
+            totals: Summary::of(&totals),
+            histogram: histogram(&totals, buckets),
+        };
+    }
+
+    // The overall rows are left out when there are no elves at all
+    fn rows(&self) -> Vec<(String, &Summary)> {
+        let mut rows = self
+            .elves
+            .iter()
+            .enumerate()
+            .map(|(i, summary)| (format!("elf {}", i + 1), summary))
+            .collect::<Vec<(String, &Summary)>>();
+        rows.extend(self.items.iter().map(|s| (String::from("all items"), s)));
+        rows.extend(self.totals.iter().map(|s| (String::from("elf totals"), s)));
+        return rows;
+    }
+
+    fn header() -> Vec<String> {
+        let mut header = ["", "count", "sum", "min", "max", "mean", "median"]
+            .iter()
+            .map(|h| h.to_string())
+            .collect::<Vec<String>>();
+        header.extend(PERCENTILES.iter().map(|p| format!("p{}", p)));
+        return header;
+    }
+
+    fn cells(s: &Summary) -> Vec<String> {
+        let mut cells = vec![
+            s.count.to_string(),
+            s.sum.to_string(),
+            s.min.to_string(),
+            s.max.to_string(),
+            format!("{:.1}", s.mean),
+            format!("{:.1}", s.median),
+        ];
+        cells.extend(s.percentiles.iter().map(|p| format!("{:.1}", p)));
+        return cells;
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut table = vec![Self::header()];
+        for (name, summary) in self.rows() {
+            let mut row = vec![name];
+            row.extend(Self::cells(summary));
+            table.push(row);
+        }
+        let widths = (0..table[0].len())
+            .map(|i| table.iter().map(|row| row[i].len()).max().unwrap_or(0))
+            .collect::<Vec<usize>>();
+
+        let mut lines = table
+            .iter()
+            .map(|row| {
+                let mut line = format!("{:<w$}", row[0], w = widths[0]);
+                for (cell, w) in row.iter().zip(&widths).skip(1) {
+                    line += &format!("  {:>w$}", cell, w = w);
+                }
+                line
+            })
+            .collect::<Vec<String>>();
+
+        lines.push(String::new());
+        lines.push(String::from("Histogram of elf totals"));
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let width = self.histogram.last().map_or(0, |b| b.to.to_string().len());
+        for b in &self.histogram {
+            let bar = "#".repeat((b.elves * 40).div_ceil(most.max(1)));
+            lines.push(format!(
+                "{:>w$} - {:>w$} | {} {}",
+                b.from,
+                b.to,
+                bar,
+                b.elves,
+                w = width
+            ));
+        }
+        return lines.join("\n");
+    }
+
+    // Two tables separated by a blank line: the summaries, then the histogram
+    pub fn to_csv(&self) -> String {
+        let mut header = Self::header();
+        header[0] = String::from("scope");
+        let mut lines = vec![header.join(",")];
+        for (name, summary) in self.rows() {
+            lines.push(format!("{},{}", name, Self::cells(summary).join(",")));
+        }
+        lines.push(String::new());
+        lines.push(String::from("from,to,elves"));
+        for b in &self.histogram {
+            lines.push(format!("{},{},{}", b.from, b.to, b.elves));
+        }
+        return lines.join("\n");
+    }
+}
@@ -0,0 +1,57 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_1::part1(EXAMPLE), Ok(24000));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_1::part2(EXAMPLE), Ok(45000));
+}
+
+#[test]
+fn top_elves_example() {
+    let top = day_1::top_elves(EXAMPLE.as_bytes(), 3).unwrap();
+    assert_eq!(
+        top,
+        vec![
+            day_1::RankedElf {
+                index: 4,
+                calories: 24000,
+                items: vec![7000, 8000, 9000],
+            },
+            day_1::RankedElf {
+                index: 3,
+                calories: 11000,
+                items: vec![5000, 6000],
+            },
+            day_1::RankedElf {
+                index: 5,
+                calories: 10000,
+                items: vec![10000],
+            },
+        ]
+    );
+    assert_eq!(
+        day_1::part2_with(EXAMPLE, &day_1::Params { top: 10 }),
+        Ok(55000)
+    );
+}
+
+#[test]
+fn crlf_and_loose_blank_lines() {
+    let messy = EXAMPLE
+        .replace('\n', " \r\n")
+        .replace(" \r\n \r\n", "\r\n\t\r\n\r\n");
+    assert_eq!(day_1::part1(&messy), Ok(24000));
+    assert_eq!(day_1::part2(&messy), Ok(45000));
+    assert_eq!(day_1::parse_elves(&messy).unwrap().len(), 5);
+}
+
+#[test]
+fn malformed_value_names_its_group() {
+    let error = day_1::part1("100\n\n200\n2x0\n").unwrap_err();
+    assert_eq!((error.line, error.column), (4, 1));
+    assert_eq!(error.message, "expected usize in group 2");
+}
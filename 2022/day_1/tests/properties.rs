@@ -0,0 +1,64 @@
+use aoc_common::Rng;
+
+fn naive_totals(input: &str) -> Vec<usize> {
+    let mut totals = input
+        .split("\n\n")
+        .map(|elf| elf.lines().map(|l| l.parse::<usize>().unwrap()).sum())
+        .collect::<Vec<usize>>();
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    totals
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_1::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let totals = naive_totals(&input);
+        assert_eq!(day_1::part1(&input), Ok(totals[0]), "seed {}", seed);
+        assert_eq!(
+            day_1::part2(&input),
+            Ok(totals.iter().take(3).sum()),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn top_elves_match_a_full_sort() {
+    for seed in 0..30 {
+        let input = day_1::generate(&mut Rng::new(seed), 1 + seed as usize * 3);
+        let mut ranked = input
+            .split("\n\n")
+            .enumerate()
+            .map(|(i, elf)| {
+                let items = elf
+                    .lines()
+                    .map(|l| l.parse::<usize>().unwrap())
+                    .collect::<Vec<usize>>();
+                (items.iter().sum::<usize>(), i + 1, items)
+            })
+            .collect::<Vec<(usize, usize, Vec<usize>)>>();
+        ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        for n in [0, 1, 3, 10, ranked.len() + 5] {
+            let top = day_1::top_elves(input.as_bytes(), n).unwrap();
+            let expected = ranked.iter().take(n).cloned().collect::<Vec<_>>();
+            let actual = top
+                .into_iter()
+                .map(|elf| (elf.calories, elf.index, elf.items))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "seed {} n {}", seed, n);
+        }
+    }
+}
+
+#[test]
+fn ties_keep_the_earlier_elf() {
+    let top = day_1::top_elves("5\n\n7\n\n2\n3\n\n\n7\n".as_bytes(), 3).unwrap();
+    let ranked = top
+        .iter()
+        .map(|elf| (elf.index, elf.calories))
+        .collect::<Vec<(usize, usize)>>();
+    assert_eq!(ranked, vec![(2, 7), (4, 7), (1, 5)]);
+}
@@ -0,0 +1,130 @@
+use day_1::report::{histogram, Bucket, Report, Summary};
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+fn example_report(buckets: usize) -> Report {
+    Report::new(&day_1::parse_elves(EXAMPLE).unwrap(), buckets)
+}
+
+#[test]
+fn example_summaries() {
+    let report = example_report(2);
+    assert_eq!(report.elves.len(), 5);
+    assert_eq!(
+        report.elves[3],
+        Summary {
+            count: 3,
+            sum: 24000,
+            min: 7000,
+            max: 9000,
+            mean: 8000.0,
+            median: 8000.0,
+            percentiles: vec![7200.0, 7500.0, 8500.0, 8800.0, 8980.0],
+        }
+    );
+
+    let items = report.items.unwrap();
+    assert_eq!(
+        (items.count, items.sum, items.min, items.max),
+        (10, 55000, 1000, 10000)
+    );
+    assert_eq!((items.mean, items.median), (5500.0, 5500.0));
+    assert_eq!(items.percentiles[..2], [1900.0, 3250.0]);
+
+    let totals = report.totals.unwrap();
+    assert_eq!(
+        (totals.count, totals.sum, totals.min, totals.max),
+        (5, 55000, 4000, 24000)
+    );
+    assert_eq!((totals.mean, totals.median), (11000.0, 10000.0));
+}
+
+#[test]
+fn single_value_and_empty_summaries() {
+    let single = Summary::of(&[42]).unwrap();
+    assert_eq!((single.mean, single.median), (42.0, 42.0));
+    assert!(single.percentiles.iter().all(|&p| p == 42.0));
+    assert_eq!(Summary::of(&[]), None);
+}
+
+#[test]
+fn histogram_buckets() {
+    assert_eq!(
+        example_report(2).histogram,
+        vec![
+            Bucket {
+                from: 4000,
+                to: 14000,
+                elves: 4
+            },
+            Bucket {
+                from: 14001,
+                to: 24001,
+                elves: 1
+            },
+        ]
+    );
+    // Fewer distinct totals than buckets never yields empty trailing buckets
+    assert_eq!(
+        histogram(&[5, 5, 6], 10),
+        vec![
+            Bucket {
+                from: 5,
+                to: 5,
+                elves: 2
+            },
+            Bucket {
+                from: 6,
+                to: 6,
+                elves: 1
+            },
+        ]
+    );
+    assert_eq!(histogram(&[], 10), vec![]);
+}
+
+#[test]
+fn blank_lines_never_make_an_empty_elf() {
+    let report = Report::new(&day_1::parse_elves("1\n\n\n\n3\n").unwrap(), 10);
+    assert_eq!(report.elves.len(), 2);
+    assert_eq!(report.totals.unwrap().min, 1);
+
+    // Without any elves only the headers are left
+    let report = Report::new(&day_1::parse_elves("").unwrap(), 10);
+    assert!(report.elves.is_empty() && report.items.is_none() && report.histogram.is_empty());
+    assert_eq!(
+        report.to_csv(),
+        "scope,count,sum,min,max,mean,median,p10,p25,p75,p90,p99\n\nfrom,to,elves"
+    );
+}
+
+#[test]
+fn text_and_csv_output() {
+    let report = example_report(2);
+
+    let text = report.to_text();
+    let lines = text.lines().collect::<Vec<&str>>();
+    assert!(lines[0].trim_start().starts_with("count"));
+    assert!(lines[0].ends_with("p99"));
+    assert!(lines[7].starts_with("elf totals"));
+    assert!(text.ends_with(
+        "Histogram of elf totals\n\
+         \x204000 - 14000 | ######################################## 4\n\
+         14001 - 24001 | ########## 1"
+    ));
+
+    let csv = report.to_csv();
+    let lines = csv.lines().collect::<Vec<&str>>();
+    assert_eq!(
+        lines[0],
+        "scope,count,sum,min,max,mean,median,p10,p25,p75,p90,p99"
+    );
+    assert_eq!(
+        lines[1],
+        "elf 1,3,6000,1000,3000,2000.0,2000.0,1200.0,1500.0,2500.0,2800.0,2980.0"
+    );
+    assert_eq!(
+        lines[8..],
+        ["", "from,to,elves", "4000,14000,4", "14001,24001,1"]
+    );
+}
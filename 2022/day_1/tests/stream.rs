@@ -0,0 +1,24 @@
+use aoc_common::Rng;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn matches_in_memory_solution() {
+    let generated =
+        (0..20).map(|seed| day_1::generate(&mut Rng::new(seed), 1 + seed as usize * 10));
+    for input in std::iter::once(EXAMPLE.to_string()).chain(generated) {
+        assert_eq!(
+            day_1::solve_stream(input.as_bytes()),
+            Ok((day_1::part1(&input).unwrap(), day_1::part2(&input).unwrap()))
+        );
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    let input = "1000\r\n\r\n20x0\n";
+    assert_eq!(
+        day_1::solve_stream(input.as_bytes()).unwrap_err(),
+        day_1::part1(input).unwrap_err()
+    );
+}
@@ -0,0 +1,207 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    for_each_line, source_lines, Grid, ParseError, Point2, Pos, Rng, SourceLine, Visualize,
+};
+
+#[derive(Clone)]
+pub enum Instruction {
+    ADDX(isize),
+    NOOP,
+}
+
+impl Instruction {
+    fn parse(ins_line: &SourceLine) -> Result<Self, ParseError> {
+        let mut values = ins_line.text.split_whitespace();
+        let cmd = values
+            .next()
+            .ok_or_else(|| ins_line.error(ins_line.end(), "missing instruction"))?;
+        return match cmd {
+            "addx" => Ok(Instruction::ADDX(
+                ins_line.parse::<isize>(
+                    values
+                        .next()
+                        .ok_or_else(|| ins_line.error(ins_line.end(), "missing 'addx' value"))?,
+                )?,
+            )),
+            "noop" => Ok(Instruction::NOOP),
+            _ => Err(ins_line.error(cmd, "unknown instruction")),
+        };
+    }
+}
+
+pub struct Screen {
+    pub canvas: Grid<char>,
+    sprite_x: isize,
+    current_pixel: Pos,
+}
+
+impl Screen {
+    const WIDTH: usize = 40;
+    const HEIGHT: usize = 6;
+
+    fn new() -> Self {
+        Self {
+            canvas: Grid::new(Screen::WIDTH, Screen::HEIGHT, '.'),
+            sprite_x: 1,
+            current_pixel: Point2::new(0, 0),
+        }
+    }
+
+    fn draw_current_pixel(&mut self) {
+        // The sprite is three pixels wide and may hang partly or fully off screen
+        if (self.current_pixel.x as isize - self.sprite_x).abs() <= 1 {
+            if let Some(pixel) = self.canvas.get_mut(self.current_pixel) {
+                *pixel = '#';
+            }
+        }
+        if self.current_pixel.x > 0 && self.current_pixel.x.is_multiple_of(39) {
+            self.current_pixel.x = 0;
+            self.current_pixel.y += 1;
+        } else {
+            self.current_pixel.x += 1;
+        }
+    }
+
+    fn update_sprite_pos(&mut self, x: isize) {
+        self.sprite_x = x;
+    }
+
+    pub fn render(&self) -> String {
+        self.canvas.to_string()
+    }
+}
+
+pub struct CPU {
+    pub x: isize,
+    wait: isize,
+    pub cycles: isize,
+    pub signal_strength: isize,
+    pub screen: Screen,
+}
+
+impl CPU {
+    fn new() -> Self {
+        return Self {
+            x: 1,
+            wait: 0,
+            cycles: 0,
+            signal_strength: 0,
+            screen: Screen::new(),
+        };
+    }
+
+    fn execute_instruction(
+        &mut self,
+        current_instruction: &Instruction,
+        on_cycle: &mut dyn FnMut(&Screen),
+    ) {
+        match current_instruction {
+            Instruction::ADDX(value) => {
+                self.wait = 2;
+                while self.wait > 0 {
+                    self.cycles += 1;
+                    self.screen.draw_current_pixel();
+                    on_cycle(&self.screen);
+                    self.signal_strength += self.calculate_signal_strength();
+                    self.wait -= 1;
+                }
+
+                self.x += value;
+
+                self.screen.update_sprite_pos(self.x);
+            }
+            Instruction::NOOP => {
+                self.cycles += 1;
+                self.signal_strength += self.calculate_signal_strength();
+                self.screen.draw_current_pixel();
+                on_cycle(&self.screen);
+            }
+        }
+    }
+
+    fn calculate_signal_strength(&self) -> isize {
+        if self.cycles == 20 || (self.cycles - 20) % 40 == 0 {
+            return self.x * self.cycles;
+        }
+        return 0;
+    }
+}
+
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    source_lines(input)
+        .map(|line| Instruction::parse(&line))
+        .collect::<Result<Vec<Instruction>, ParseError>>()
+}
+
+pub fn run_program(input: &str) -> Result<CPU, ParseError> {
+    let mut cpu = CPU::new();
+
+    for instruction in parse_program(input)? {
+        cpu.execute_instruction(&instruction, &mut |_| ());
+    }
+    return Ok(cpu);
+}
+
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(isize, String), ParseError> {
+    let mut cpu = CPU::new();
+
+    for_each_line(reader, |line| {
+        cpu.execute_instruction(&Instruction::parse(&line)?, &mut |_| ());
+        return Ok(());
+    })?;
+    return Ok((cpu.signal_strength, cpu.screen.render()));
+}
+
+pub struct Crt {
+    program: Vec<Instruction>,
+}
+
+impl Crt {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            program: parse_program(input)?,
+        })
+    }
+}
+
+impl Visualize for Crt {
+    fn visualize(&mut self, frame: &mut dyn FnMut(&Grid<char>)) {
+        let mut cpu = CPU::new();
+        let mut draw = |screen: &Screen| {
+            let mut canvas = screen.canvas.clone();
+            if let Some(beam) = canvas.get_mut(screen.current_pixel) {
+                *beam = '@';
+            }
+            frame(&canvas);
+        };
+        for instruction in &self.program {
+            cpu.execute_instruction(instruction, &mut draw);
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<isize, ParseError> {
+    Ok(run_program(input)?.signal_strength)
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(run_program(input)?.screen.render())
+}
+
+// `size` instructions; the register may drift just past either edge of the
+// screen, and cycles after the first 240 only count towards part 1
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let mut x: i64 = 1;
+    for _ in 0..size.max(1) {
+        if rng.chance(1, 3) {
+            out += "noop\n";
+        } else {
+            let value = rng.range((-2 - x).max(-15)..=(42 - x).min(15));
+            out += &format!("addx {}\n", value);
+            x += value;
+        }
+    }
+    return out;
+}
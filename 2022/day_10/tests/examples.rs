@@ -0,0 +1,41 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+const SMALL_EXAMPLE: &str = include_str!("../test_input_p1_small");
+
+#[test]
+fn small_example_registers() {
+    let cpu = day_10::run_program(SMALL_EXAMPLE).unwrap();
+    assert_eq!(cpu.x, -1);
+    assert_eq!(cpu.cycles, 5);
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_10::part1(EXAMPLE), Ok(13140));
+}
+
+#[test]
+fn part2_example() {
+    let screen = [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ];
+    assert_eq!(day_10::part2(EXAMPLE), Ok(screen.join("\n")));
+}
+
+#[test]
+fn sprite_hanging_off_either_edge() {
+    // At x = 0 the sprite covers pixels -1 to 1, so pixel 2 stays dark
+    let left = day_10::part2("addx -1\nnoop\nnoop\n").unwrap();
+    assert_eq!(&left[..4], "##..");
+
+    // At x = 41 the sprite covers pixels 40 to 42, all off screen
+    let right = day_10::part2(&format!("addx 40\n{}", "noop\n".repeat(38))).unwrap();
+    assert_eq!(
+        right.lines().next(),
+        Some(format!("##{}", ".".repeat(38)).as_str())
+    );
+}
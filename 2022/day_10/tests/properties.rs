@@ -0,0 +1,54 @@
+use aoc_common::Rng;
+
+// The value of the register during each cycle, starting with cycle 1
+fn naive_register(input: &str) -> Vec<isize> {
+    let mut x = 1;
+    let mut values = vec![];
+    for line in input.lines() {
+        values.push(x);
+        if let Some(value) = line.strip_prefix("addx ") {
+            values.push(x);
+            x += value.parse::<isize>().unwrap();
+        }
+    }
+    values
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let size = 1 + seed as usize * 10;
+        let input = day_10::generate(&mut Rng::new(seed), size);
+        assert_eq!(input.lines().count(), size);
+        let values = naive_register(&input);
+
+        let part1 = (20..=values.len())
+            .step_by(40)
+            .map(|cycle| cycle as isize * values[cycle - 1])
+            .sum();
+        // Pixels after the last cycle stay dark
+        let mut screen = values
+            .iter()
+            .map(|&x| Some(x))
+            .collect::<Vec<Option<isize>>>();
+        screen.resize(240, None);
+        let part2 = screen
+            .chunks(40)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(col, x)| {
+                        if x.is_some_and(|x| (col as isize - x).abs() <= 1) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        assert_eq!(day_10::part1(&input), Ok(part1), "seed {}", seed);
+        assert_eq!(day_10::part2(&input), Ok(part2), "seed {}", seed);
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_common::Rng;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn matches_in_memory_solution() {
+    let generated = (0..20).map(|seed| day_10::generate(&mut Rng::new(seed), 0));
+    for input in std::iter::once(EXAMPLE.to_string()).chain(generated) {
+        assert_eq!(
+            day_10::solve_stream(input.as_bytes()),
+            Ok((
+                day_10::part1(&input).unwrap(),
+                day_10::part2(&input).unwrap()
+            ))
+        );
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    let input = "noop\naddx\n";
+    assert_eq!(
+        day_10::solve_stream(input.as_bytes()).unwrap_err(),
+        day_10::part1(input).unwrap_err()
+    );
+}
@@ -0,0 +1,366 @@
+use aoc_common::{groups, Group, ParseError, Rng, SourceLine};
+
+pub struct Params {
+    pub rounds: isize,
+    pub limited_rounds: isize,
+    pub top_monkeys: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            rounds: 20,
+            limited_rounds: 10_000,
+            top_monkeys: 2,
+        }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "rounds" => self.rounds = value.parse().map_err(|_| invalid())?,
+            "limited_rounds" => self.limited_rounds = value.parse().map_err(|_| invalid())?,
+            "top_monkeys" => self.top_monkeys = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Monkey {
+    pub starting_items: Vec<isize>,
+    pub throw_to_true: isize,
+    pub throw_to_false: isize,
+    pub inspect_counter: isize,
+    operation_fn: Option<Box<dyn Fn(isize, isize) -> isize>>,
+    operation_val: isize,
+    test_fn: Option<Box<dyn Fn(isize, isize) -> bool>>,
+    pub test_val: isize,
+}
+
+impl Monkey {
+    fn new() -> Self {
+        Self {
+            starting_items: vec![],
+            throw_to_true: -1,
+            throw_to_false: -1,
+            inspect_counter: 0,
+            operation_fn: None,
+            operation_val: 0,
+            test_fn: None,
+            test_val: -1,
+        }
+    }
+
+    fn parse_starting_items(&mut self, line: SourceLine) -> Result<(), ParseError> {
+        let items = line.strip_prefix(line.text.trim_start(), "Starting items: ")?;
+        self.starting_items = items
+            .split(", ")
+            .map(|x| line.parse::<isize>(x))
+            .collect::<Result<Vec<isize>, ParseError>>()?;
+        return Ok(());
+    }
+
+    fn parse_operation(&mut self, line: SourceLine) -> Result<(), ParseError> {
+        let mut expr_mask: u32 = 0;
+        let expression = line.strip_prefix(line.text.trim_start(), "Operation: new = ")?;
+
+        for el in expression.split_whitespace() {
+            match el {
+                "old" => expr_mask |= 1 << 0,
+                "*" => expr_mask |= 1 << 1,
+                "+" => expr_mask |= 1 << 2,
+                _ => {
+                    self.operation_val = line.parse::<isize>(el)?;
+                    expr_mask |= 1 << 3
+                }
+            }
+        }
+
+        self.operation_fn = match expr_mask {
+            0b0011 => Some(Box::new(|old, _| old * old)),
+            0b0101 => Some(Box::new(|old, _| old + old)),
+            0b1011 => Some(Box::new(|old, new| old * new)),
+            0b1101 => Some(Box::new(|old, new| old + new)),
+            _ => return Err(line.error(expression, "unsupported operation")),
+        };
+        return Ok(());
+    }
+
+    fn parse_test_condition(&mut self, line: SourceLine) -> Result<(), ParseError> {
+        let value = line.strip_prefix(line.text.trim_start(), "Test: divisible by ")?;
+        self.test_val = line.parse::<isize>(value)?;
+        if self.test_val == 0 {
+            return Err(line.error(value, "divisor must not be zero"));
+        }
+        self.test_fn = Some(Box::new(|x, y| x % y == 0));
+        return Ok(());
+    }
+
+    fn parse_test_condition_true(&mut self, line: SourceLine) -> Result<(), ParseError> {
+        let value = line.strip_prefix(line.text.trim_start(), "If true: throw to monkey ")?;
+        self.throw_to_true = line.parse::<isize>(value)?;
+        return Ok(());
+    }
+
+    fn parse_test_condition_false(&mut self, line: SourceLine) -> Result<(), ParseError> {
+        let value = line.strip_prefix(line.text.trim_start(), "If false: throw to monkey ")?;
+        self.throw_to_false = line.parse::<isize>(value)?;
+        return Ok(());
+    }
+}
+
+pub struct Game {
+    pub monkeys: Vec<Monkey>,
+}
+
+impl Game {
+    fn new() -> Self {
+        Self { monkeys: vec![] }
+    }
+
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let mut game: Game = Game::new();
+        game.parse_input(groups(input))?;
+        return Ok(game);
+    }
+
+    fn add_monkey(&mut self) -> &mut Monkey {
+        self.monkeys.push(Monkey::new());
+        return self.monkeys.last_mut().unwrap();
+    }
+
+    fn parse_input<'a>(
+        &mut self,
+        groups: impl Iterator<Item = Group<'a>>,
+    ) -> Result<(), ParseError> {
+        let mut targets = vec![];
+
+        for group in groups {
+            self.parse_monkey(&group, &mut targets)
+                .map_err(|e| group.context(e))?;
+        }
+
+        for (line, target) in targets {
+            if target < 0 || target as usize >= self.monkeys.len() {
+                let token = line.text.split_whitespace().last().unwrap_or(line.end());
+                return Err(line.error(token, "unknown monkey"));
+            }
+        }
+        return Ok(());
+    }
+
+    // One monkey per group: its header followed by exactly five attribute lines
+    fn parse_monkey<'a>(
+        &mut self,
+        group: &Group<'a>,
+        targets: &mut Vec<(SourceLine<'a>, isize)>,
+    ) -> Result<(), ParseError> {
+        let header = group.first();
+        if !header.text.starts_with("Monkey") {
+            return Err(header.error(header.text, "expected 'Monkey'"));
+        }
+        let mut lines = group.lines[1..].iter().copied();
+        let mut last_line = header;
+        let mut next_line = || {
+            let next = lines
+                .next()
+                .ok_or_else(|| last_line.error(last_line.end(), "unexpected end of monkey"))?;
+            last_line = next;
+            return Ok::<SourceLine, ParseError>(next);
+        };
+        let current_monkey: &mut Monkey = self.add_monkey();
+        current_monkey.parse_starting_items(next_line()?)?;
+        current_monkey.parse_operation(next_line()?)?;
+        current_monkey.parse_test_condition(next_line()?)?;
+        let true_line = next_line()?;
+        current_monkey.parse_test_condition_true(true_line)?;
+        let false_line = next_line()?;
+        current_monkey.parse_test_condition_false(false_line)?;
+        targets.push((true_line, current_monkey.throw_to_true));
+        targets.push((false_line, current_monkey.throw_to_false));
+
+        if let Some(extra) = lines.next() {
+            return Err(extra.error(extra.text, "expected a blank line after the monkey"));
+        }
+        return Ok(());
+    }
+
+    pub fn play(&mut self, rounds: isize, limit: Option<isize>) {
+        for _ in 0..rounds {
+            for i in 0..self.monkeys.len() {
+                let mut move_to = vec![];
+                for j in 0..self.monkeys[i].starting_items.len() {
+                    let mut current_worry = self.monkeys[i]
+                        .operation_fn
+                        .as_ref()
+                        .expect("Operation should evaluate expression.")(
+                        self.monkeys[i].starting_items[j],
+                        self.monkeys[i].operation_val,
+                    );
+
+                    if let Some(limit) = limit {
+                        current_worry %= limit;
+                        self.monkeys[i].starting_items[j] = current_worry;
+                    } else {
+                        current_worry /= 3;
+                        self.monkeys[i].starting_items[j] = current_worry;
+                    }
+
+                    if self.monkeys[i]
+                        .test_fn
+                        .as_ref()
+                        .expect("Test should return evaluation result.")(
+                        current_worry,
+                        self.monkeys[i].test_val,
+                    ) {
+                        move_to.push(self.monkeys[i].throw_to_true);
+                    } else {
+                        move_to.push(self.monkeys[i].throw_to_false);
+                    }
+
+                    self.monkeys[i].inspect_counter += 1;
+                }
+
+                move_to.iter().for_each(|x| {
+                    let val = self.monkeys[i].starting_items.remove(0);
+                    self.monkeys[*x as usize].starting_items.push(val);
+                });
+            }
+        }
+    }
+
+    pub fn get_monkey_business_score(&mut self, range: usize) -> isize {
+        self.monkeys.sort_by_key(|a| a.inspect_counter);
+
+        return self
+            .monkeys
+            .iter()
+            .rev()
+            .take(range)
+            .fold(1, |acc, x| acc * x.inspect_counter);
+    }
+}
+
+pub fn part1(input: &str) -> Result<isize, ParseError> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<isize, ParseError> {
+    let mut game: Game = Game::from_input(input)?;
+    game.play(params.rounds, None);
+
+    Ok(game.get_monkey_business_score(params.top_monkeys))
+}
+
+pub fn part2(input: &str) -> Result<isize, ParseError> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<isize, ParseError> {
+    let mut game: Game = Game::from_input(input)?;
+    let limit = game.monkeys.iter().fold(1, |acc, x| acc * x.test_val);
+    game.play(params.limited_rounds, Some(limit));
+
+    Ok(game.get_monkey_business_score(params.top_monkeys))
+}
+
+struct GeneratedMonkey {
+    items: Vec<i128>,
+    multiply: bool,
+    operand: Option<i128>,
+    divisor: i128,
+    targets: [usize; 2],
+}
+
+impl GeneratedMonkey {
+    fn inspect(&self, worry: i128) -> i128 {
+        let operand = self.operand.unwrap_or(worry);
+        if self.multiply {
+            worry * operand
+        } else {
+            worry + operand
+        }
+    }
+}
+
+// Unbounded worry levels in part 1 must stay well inside the solver's integers
+fn fits_part1(monkeys: &[GeneratedMonkey], rounds: usize) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|m| m.items.clone())
+        .collect::<Vec<Vec<i128>>>();
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for worry in std::mem::take(&mut items[i]) {
+                let worry = monkey.inspect(worry) / 3;
+                if worry > 1 << 40 {
+                    return false;
+                }
+                let target = monkey.targets[(worry % monkey.divisor != 0) as usize];
+                items[target].push(worry);
+            }
+        }
+    }
+    return true;
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(3, 8);
+    let monkeys = loop {
+        let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut divisors);
+        let squaring = rng.index(count);
+        let monkeys = (0..count)
+            .map(|i| {
+                let (multiply, operand) = match i == squaring {
+                    true => (true, None),
+                    false if rng.chance(1, 2) => (true, Some(rng.range(2..=19) as i128)),
+                    false => (false, Some(rng.range(1..=8) as i128)),
+                };
+                let on_true = (i + 1 + rng.index(count - 1)) % count;
+                let mut on_false = (i + 1 + rng.index(count - 1)) % count;
+                if on_false == on_true {
+                    on_false = (0..count).find(|&t| t != i && t != on_true).unwrap();
+                }
+                GeneratedMonkey {
+                    items: (0..rng.range(1..=6))
+                        .map(|_| rng.range(50..=99) as i128)
+                        .collect(),
+                    multiply,
+                    operand,
+                    divisor: divisors[i],
+                    targets: [on_true, on_false],
+                }
+            })
+            .collect::<Vec<GeneratedMonkey>>();
+        if fits_part1(&monkeys, Params::default().rounds as usize) {
+            break monkeys;
+        }
+    };
+
+    let mut out = vec![];
+    for (i, m) in monkeys.iter().enumerate() {
+        let items = m
+            .items
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<String>>();
+        let operand = m.operand.map_or(String::from("old"), |v| v.to_string());
+        out.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  \
+             Test: divisible by {}\n    If true: throw to monkey {}\n    \
+             If false: throw to monkey {}\n",
+            i,
+            items.join(", "),
+            if m.multiply { "*" } else { "+" },
+            operand,
+            m.divisor,
+            m.targets[0],
+            m.targets[1]
+        ));
+    }
+    return out.join("\n");
+}
@@ -0,0 +1,25 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_11::part1(EXAMPLE), Ok(10605));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_11::part2(EXAMPLE), Ok(2713310158));
+}
+
+#[test]
+fn monkeys_may_be_separated_by_any_blank_lines() {
+    let messy = EXAMPLE.replace("\n\n", "\n  \n\n").replace('\n', "\r\n");
+    assert_eq!(day_11::part1(&messy), Ok(10605));
+}
+
+#[test]
+fn incomplete_monkey_names_its_group() {
+    let truncated = EXAMPLE.replacen("    If false: throw to monkey 3\n", "", 1);
+    let error = day_11::part1(&truncated).unwrap_err();
+    assert_eq!(error.line, 5);
+    assert_eq!(error.message, "unexpected end of monkey in group 1");
+}
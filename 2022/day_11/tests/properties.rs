@@ -0,0 +1,82 @@
+use aoc_common::Rng;
+
+struct NaiveMonkey {
+    items: Vec<i128>,
+    operation: Vec<String>,
+    divisor: i128,
+    targets: [usize; 2],
+}
+
+fn last_number(line: &str) -> i128 {
+    line.split(' ').next_back().unwrap().parse().unwrap()
+}
+
+fn naive_monkey_business(input: &str, rounds: usize, relief: bool) -> isize {
+    let mut monkeys = input
+        .split("\n\n")
+        .map(|block| {
+            let lines = block.lines().collect::<Vec<&str>>();
+            NaiveMonkey {
+                items: lines[1]
+                    .split(": ")
+                    .nth(1)
+                    .unwrap()
+                    .split(", ")
+                    .map(|w| w.parse().unwrap())
+                    .collect(),
+                operation: lines[2]
+                    .split(" = ")
+                    .nth(1)
+                    .unwrap()
+                    .split(' ')
+                    .map(String::from)
+                    .collect(),
+                divisor: last_number(lines[3]),
+                targets: [
+                    last_number(lines[4]) as usize,
+                    last_number(lines[5]) as usize,
+                ],
+            }
+        })
+        .collect::<Vec<NaiveMonkey>>();
+    let modulus = monkeys.iter().map(|m| m.divisor).product::<i128>();
+
+    let mut inspected = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for worry in std::mem::take(&mut monkeys[i].items) {
+                let operand = |s: &str| s.parse::<i128>().unwrap_or(worry);
+                let op = &monkeys[i].operation;
+                let mut worry = match op[1].as_str() {
+                    "*" => operand(&op[0]) * operand(&op[2]),
+                    _ => operand(&op[0]) + operand(&op[2]),
+                };
+                worry = if relief { worry / 3 } else { worry % modulus };
+                let target = monkeys[i].targets[(worry % monkeys[i].divisor != 0) as usize];
+                monkeys[target].items.push(worry);
+                inspected[i] += 1;
+            }
+        }
+    }
+    inspected.sort_unstable_by(|a, b| b.cmp(a));
+    inspected[0] * inspected[1]
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..20 {
+        let input = day_11::generate(&mut Rng::new(seed), 3 + seed as usize % 6);
+        assert_eq!(
+            day_11::part1(&input),
+            Ok(naive_monkey_business(&input, 20, true)),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_11::part2(&input),
+            Ok(naive_monkey_business(&input, 10_000, false)),
+            "seed {}",
+            seed
+        );
+    }
+}
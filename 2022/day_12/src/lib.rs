@@ -0,0 +1,134 @@
+use aoc_common::{
+    bfs, bfs_distances, source_lines, Graph, Grid, ParseError, Point2, Pos, Rng, SourceLine,
+    Visualize,
+};
+
+pub struct Map {
+    pub map: Grid<isize>,
+    pub shortest_path_from_start: isize,
+    pub overall_shortest_path: isize,
+    pub start_pos: Vec<Pos>,
+    pub end_pos: Pos,
+}
+
+impl Map {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let chars = Grid::parse(input, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            _ => Err("invalid elevation, expected a-z, S or E"),
+        })?;
+        let last_line = source_lines(input).last().unwrap_or(SourceLine::new(0, ""));
+
+        let start = chars
+            .position(|&c| c == 'S')
+            .ok_or_else(|| last_line.error(last_line.end(), "missing start position 'S'"))?;
+        let end_pos = chars
+            .position(|&c| c == 'E')
+            .ok_or_else(|| last_line.error(last_line.end(), "missing end position 'E'"))?;
+
+        let mut start_pos = vec![start];
+        start_pos.extend(chars.iter().filter(|(_, &c)| c == 'a').map(|(pos, _)| pos));
+
+        let map = chars.map(|&c| match c {
+            'S' => 'a' as isize,
+            'E' => 'z' as isize,
+            _ => c as isize,
+        });
+        return Ok(Self {
+            map,
+            shortest_path_from_start: 0,
+            overall_shortest_path: 0,
+            start_pos,
+            end_pos,
+        });
+    }
+
+    pub fn find_shortest_path(&mut self, start_pos: Pos) -> Result<(), ParseError> {
+        let path = bfs(&*self, [start_pos], |&p| p == self.end_pos)
+            .ok_or_else(|| ParseError::whole_input("no path from S to E"))?;
+        self.shortest_path_from_start = path.cost as isize;
+        return Ok(());
+    }
+
+    pub fn find_all_shortest_paths(&mut self) -> Result<(), ParseError> {
+        // A single search from every start at once finds the closest one
+        let starts = self.start_pos.clone();
+        let path = bfs(&*self, starts, |&p| p == self.end_pos)
+            .ok_or_else(|| ParseError::whole_input("no path from S or any 'a' to E"))?;
+        self.overall_shortest_path = path.cost as isize;
+        return Ok(());
+    }
+}
+
+impl Graph for Map {
+    type Node = Pos;
+
+    fn neighbours(&self, pos: &Pos) -> impl Iterator<Item = Pos> {
+        self.map
+            .neighbours4(*pos)
+            .filter(move |&next| self.map[next] - self.map[*pos] <= 1)
+    }
+}
+
+// Unexplored squares show their elevation, the BFS frontier is drawn as '#'
+impl Visualize for Map {
+    fn visualize(&mut self, frame: &mut dyn FnMut(&Grid<char>)) {
+        let start = self.start_pos[0];
+        let distances = bfs_distances(&*self, [start]);
+        let path = bfs(&*self, [start], |&p| p == self.end_pos);
+        let last_level = match &path {
+            Some(path) => path.cost,
+            None => distances.values().copied().max().unwrap_or(0),
+        };
+
+        let mut grid = self.map.map(|&h| h as u8 as char);
+        grid[self.end_pos] = 'E';
+        for level in 0..=last_level {
+            for (&pos, &distance) in &distances {
+                if distance + 1 == level {
+                    grid[pos] = '.';
+                } else if distance == level {
+                    grid[pos] = '#';
+                }
+            }
+            frame(&grid);
+        }
+        if let Some(path) = path {
+            for pos in path.nodes {
+                grid[pos] = '*';
+            }
+            frame(&grid);
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<isize, ParseError> {
+    let mut map: Map = Map::from_input(input)?;
+    map.find_shortest_path(map.start_pos[0])?;
+    Ok(map.shortest_path_from_start)
+}
+
+pub fn part2(input: &str) -> Result<isize, ParseError> {
+    let mut map: Map = Map::from_input(input)?;
+    map.find_all_shortest_paths()?;
+    Ok(map.overall_shortest_path)
+}
+
+// The terrain rises from west to east with random walls and pits; one row and
+// the first and last columns are left smooth so the summit is always reachable
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(26), (size / 3).max(3));
+    let smooth_row = rng.index(height);
+    let mut grid = Grid::new(width, height, 'a');
+    for pos in grid.positions().collect::<Vec<Pos>>() {
+        let ramp = (pos.x * 25 / (width - 1)) as i64;
+        let elevation = match pos.x == 0 || pos.x == width - 1 || pos.y == smooth_row {
+            true => ramp,
+            false => (ramp + rng.range(-3..=2)).clamp(0, 25),
+        };
+        grid[pos] = (b'a' + elevation as u8) as char;
+    }
+    grid[Point2::new(0, rng.index(height))] = 'S';
+    grid[Point2::new(width - 1, rng.index(height))] = 'E';
+    return grid.to_string() + "\n";
+}
@@ -0,0 +1,21 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_12::part1(EXAMPLE), Ok(31));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_12::part2(EXAMPLE), Ok(29));
+}
+
+#[test]
+fn unreachable_summit_is_an_error() {
+    // The only way to E is a climb of two
+    let input = "SbcE\nabcz\n";
+    let error = day_12::part1(input).unwrap_err();
+    assert_eq!(error.to_string(), "no path from S to E");
+    let error = day_12::part2(input).unwrap_err();
+    assert_eq!(error.to_string(), "no path from S or any 'a' to E");
+}
@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+
+use aoc_common::Rng;
+
+fn elevation(c: u8) -> u8 {
+    match c {
+        b'S' => b'a',
+        b'E' => b'z',
+        _ => c,
+    }
+}
+
+fn naive_distance(grid: &[&[u8]], start: (usize, usize)) -> Option<usize> {
+    let (height, width) = (grid.len(), grid[0].len());
+    let mut distance = vec![vec![None; width]; height];
+    let mut queue = VecDeque::from([start]);
+    distance[start.1][start.0] = Some(0);
+    while let Some((x, y)) = queue.pop_front() {
+        let d = distance[y][x].unwrap();
+        if grid[y][x] == b'E' {
+            return Some(d);
+        }
+        let neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in neighbours {
+            if nx < width
+                && ny < height
+                && distance[ny][nx].is_none()
+                && elevation(grid[ny][nx]) <= elevation(grid[y][x]) + 1
+            {
+                distance[ny][nx] = Some(d + 1);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..20 {
+        let input = day_12::generate(&mut Rng::new(seed), 26 + seed as usize);
+        let grid = input.lines().map(str::as_bytes).collect::<Vec<&[u8]>>();
+        let cells = (0..grid.len())
+            .flat_map(|y| (0..grid[0].len()).map(move |x| (x, y)))
+            .collect::<Vec<(usize, usize)>>();
+        let start = *cells.iter().find(|&&(x, y)| grid[y][x] == b'S').unwrap();
+        let part1 = naive_distance(&grid, start).unwrap();
+        let part2 = cells
+            .iter()
+            .filter(|&&(x, y)| elevation(grid[y][x]) == b'a')
+            .filter_map(|&pos| naive_distance(&grid, pos))
+            .min()
+            .unwrap();
+        assert_eq!(day_12::part1(&input), Ok(part1 as isize), "seed {}", seed);
+        assert_eq!(day_12::part2(&input), Ok(part2 as isize), "seed {}", seed);
+    }
+}
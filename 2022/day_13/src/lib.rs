@@ -0,0 +1,460 @@
+use std::{cmp, fmt, iter::Peekable, str};
+
+use aoc_common::{groups, ParseError, Rng, SourceLine};
+
+struct Cursor<'a> {
+    line: SourceLine<'a>,
+    text: &'a str,
+    chars: Peekable<str::CharIndices<'a>>,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(line: SourceLine<'a>, text: &'a str) -> Self {
+        Self {
+            line,
+            text,
+            chars: text.char_indices().peekable(),
+        }
+    }
+
+    fn error(&self, pos: usize, c: char, message: &str) -> ParseError {
+        self.line
+            .error(&self.text[pos..pos + c.len_utf8()], message)
+    }
+}
+
+#[derive(PartialEq, Clone)]
+enum EntityType {
+    NONE,
+    VALUE,
+    LIST,
+}
+
+#[derive(PartialEq, Clone)]
+struct Entity {
+    e_type: EntityType,
+    list: Option<Vec<Entity>>,
+    value: Option<isize>,
+}
+
+impl PartialOrd for Entity {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        match (self, other) {
+            (
+                Entity {
+                    e_type: EntityType::VALUE,
+                    value: Some(l),
+                    ..
+                },
+                Entity {
+                    e_type: EntityType::VALUE,
+                    value: Some(r),
+                    ..
+                },
+            ) => l.partial_cmp(r),
+            (
+                Entity {
+                    e_type: EntityType::LIST,
+                    list: Some(l),
+                    ..
+                },
+                Entity {
+                    e_type: EntityType::LIST,
+                    list: Some(r),
+                    ..
+                },
+            ) => l.partial_cmp(r),
+            (
+                Entity {
+                    e_type: EntityType::VALUE,
+                    value: Some(l),
+                    ..
+                },
+                Entity {
+                    e_type: EntityType::LIST,
+                    list: Some(r),
+                    ..
+                },
+            ) => {
+                if r.is_empty() {
+                    return Some(cmp::Ordering::Greater);
+                }
+                return vec![Entity {
+                    e_type: EntityType::VALUE,
+                    value: Some(*l),
+                    list: None,
+                }]
+                .partial_cmp(r);
+            }
+            (
+                Entity {
+                    e_type: EntityType::LIST,
+                    list: Some(l),
+                    ..
+                },
+                Entity {
+                    e_type: EntityType::VALUE,
+                    value: Some(r),
+                    ..
+                },
+            ) => {
+                if l.is_empty() {
+                    return Some(cmp::Ordering::Less);
+                }
+                return l.partial_cmp(&vec![Entity {
+                    e_type: EntityType::VALUE,
+                    value: Some(*r),
+                    list: None,
+                }]);
+            }
+            _ => panic!("Invalid comparison"),
+        }
+    }
+}
+
+impl fmt::Display for Entity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Entity {
+                e_type: EntityType::VALUE,
+                value: Some(l),
+                ..
+            } => write!(f, "{}", l),
+            Entity {
+                e_type: EntityType::LIST,
+                list: Some(r),
+                ..
+            } => write!(
+                f,
+                "[{}]",
+                r.iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            _ => panic!("Invalid entity"),
+        }
+    }
+}
+
+impl Entity {
+    fn new() -> Self {
+        Self {
+            e_type: EntityType::NONE,
+            list: None,
+            value: None,
+        }
+    }
+
+    fn parse_inner_list(
+        p: &mut Packet,
+        cursor: &mut Cursor,
+        entity: &mut Entity,
+    ) -> Result<bool, ParseError> {
+        let mut acc_list = vec![];
+        entity.e_type = EntityType::LIST;
+        loop {
+            let e = Entity::parse(p, cursor)?;
+            match e {
+                Some(e) => {
+                    acc_list.push(e);
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+        entity.list = Some(acc_list);
+        return Ok(true);
+    }
+
+    fn parse_isize(
+        start: usize,
+        cursor: &mut Cursor,
+        entity: &mut Entity,
+    ) -> Result<bool, ParseError> {
+        let mut end = start + 1;
+        entity.e_type = EntityType::VALUE;
+        loop {
+            match cursor.chars.peek() {
+                Some((_, '0'..='9')) => end = cursor.chars.next().unwrap().0 + 1,
+                None | Some((_, ',')) | Some((_, ']')) => {
+                    entity.value = Some(cursor.line.parse::<isize>(&cursor.text[start..end])?);
+                    break;
+                }
+                Some(&(pos, c)) => {
+                    return Err(cursor.error(pos, c, "unexpected character in number"))
+                }
+            }
+        }
+        return Ok(true);
+    }
+
+    fn parse(p: &mut Packet, cursor: &mut Cursor) -> Result<Option<Entity>, ParseError> {
+        let mut entity = Entity::new();
+        loop {
+            let c = cursor.chars.next();
+            let parse_break: bool = match c {
+                Some((_, '[')) => Entity::parse_inner_list(p, cursor, &mut entity)?,
+                Some((pos, '0'..='9')) => Entity::parse_isize(pos, cursor, &mut entity)?,
+                Some((_, ',')) => false,
+                Some((_, ']')) | None => true,
+                Some((pos, c)) => return Err(cursor.error(pos, c, "unexpected character")),
+            };
+            if parse_break {
+                break;
+            }
+        }
+        if entity.e_type == EntityType::NONE {
+            return Ok(None);
+        }
+        p.items += 1;
+        return Ok(Some(entity));
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Packet {
+    items: usize,
+    entities: Vec<Entity>,
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        for i in 0..self.items + 1 {
+            if i >= self.entities.len() {
+                return Some(cmp::Ordering::Less);
+            } else if i >= other.entities.len() {
+                return Some(cmp::Ordering::Greater);
+            }
+            let l = &self.entities[i];
+            let r = &other.entities[i];
+            if l > r {
+                return Some(cmp::Ordering::Greater);
+            } else if l < r {
+                return Some(cmp::Ordering::Less);
+            }
+        }
+        unreachable!("Comparison should have returned before this point");
+    }
+}
+
+impl Packet {
+    fn new() -> Self {
+        Self {
+            items: 0,
+            entities: vec![],
+        }
+    }
+
+    pub fn from_slice(s: &str) -> Result<Self, ParseError> {
+        let mut p = Packet::new();
+        p.parse_packet(SourceLine::new(0, s))?;
+        return Ok(p);
+    }
+
+    fn parse_packet(&mut self, line: SourceLine) -> Result<(), ParseError> {
+        let inner = line
+            .text
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(|| line.error(line.text, "packet must be enclosed in '[' and ']'"))?;
+        let mut cursor = Cursor::new(line, inner);
+        while let Some(entity) = Entity::parse(self, &mut cursor)? {
+            self.entities.push(entity);
+        }
+        return Ok(());
+    }
+}
+
+pub struct Pair {
+    pub p1: Packet,
+    pub p2: Packet,
+    pub correct_order: bool,
+}
+
+impl Pair {
+    fn new() -> Self {
+        Self {
+            p1: Packet::new(),
+            p2: Packet::new(),
+            correct_order: true,
+        }
+    }
+
+    fn parse_pair(&mut self, p1_line: SourceLine, p2_line: SourceLine) -> Result<(), ParseError> {
+        self.p1.parse_packet(p1_line)?;
+        self.p2.parse_packet(p2_line)?;
+        return Ok(());
+    }
+
+    fn validate_order(&mut self) {
+        if self.p1.partial_cmp(&self.p2) == Some(cmp::Ordering::Greater) {
+            self.correct_order = false;
+        }
+    }
+}
+
+fn render_packet_list(packets: &[Packet]) -> String {
+    packets
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            format!(
+                "{:width$}: {:?}",
+                i + 1,
+                p.entities
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>(),
+                width = (packets.len() as f64).log10() as usize + 1
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let mut pairs: Vec<Pair> = vec![];
+
+    for group in groups(input) {
+        let (p1_line, p2_line) = match group.lines[..] {
+            [p1_line, p2_line] => (p1_line, p2_line),
+            [p1_line] => {
+                return Err(group.error(p1_line, p1_line.end(), "missing second packet of pair"))
+            }
+            _ => {
+                let extra = group.lines[2];
+                return Err(group.error(extra, extra.text, "expected a blank line"));
+            }
+        };
+        let mut new_pair: Pair = Pair::new();
+        new_pair
+            .parse_pair(p1_line, p2_line)
+            .map_err(|e| group.context(e))?;
+        new_pair.validate_order();
+        pairs.push(new_pair);
+    }
+    return Ok(pairs);
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let pairs = parse_pairs(input)?;
+    let sum: usize = pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, pair)| pair.correct_order)
+        .map(|(i, _)| i + 1)
+        .sum();
+
+    Ok(sum)
+}
+
+pub fn sort_packets(input: &str) -> Result<(Vec<Packet>, usize, usize), ParseError> {
+    let pairs = parse_pairs(input)?;
+    let d_2 = Packet::from_slice("[[2]]")?;
+    let d_6 = Packet::from_slice("[[6]]")?;
+
+    let mut packets: Vec<Packet> = pairs
+        .iter()
+        .flat_map(|pair| vec![pair.p1.clone(), pair.p2.clone()])
+        .collect();
+
+    packets.push(d_2.clone());
+    packets.push(d_6.clone());
+    packets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let i_2 = packets.iter().position(|p| p == &d_2).unwrap();
+    let i_6 = packets.iter().position(|p| p == &d_6).unwrap();
+
+    return Ok((packets, i_2, i_6));
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let (_, i_2, i_6) = sort_packets(input)?;
+    Ok((i_2 + 1) * (i_6 + 1))
+}
+
+pub fn render_sorted_packets(input: &str) -> Result<String, ParseError> {
+    Ok(render_packet_list(&sort_packets(input)?.0))
+}
+
+fn generate_list(rng: &mut Rng, depth: usize) -> String {
+    let items = (0..rng.range(0..=4))
+        .map(|_| match depth > 0 && rng.chance(1, 3) {
+            true => generate_list(rng, depth - 1),
+            false => rng.range(0..=10).to_string(),
+        })
+        .collect::<Vec<String>>();
+    return format!("[{}]", items.join(","));
+}
+
+fn generate_packet(rng: &mut Rng) -> String {
+    let packet = generate_list(rng, 4);
+    if rng.chance(1, 8) {
+        let depth = rng.range(20..=40) as usize;
+        return format!("[{}{}{}]", "[".repeat(depth), packet, "]".repeat(depth));
+    }
+    return packet;
+}
+
+// Pairs that only differ in one number exercise the deeper comparison rules
+fn mutate_packet(rng: &mut Rng, packet: &str) -> String {
+    let numbers = packet
+        .match_indices(|c: char| c.is_ascii_digit())
+        .filter(|(i, _)| !packet[..*i].ends_with(|c: char| c.is_ascii_digit()))
+        .map(|(i, _)| i)
+        .collect::<Vec<usize>>();
+    if numbers.is_empty() {
+        return generate_packet(rng);
+    }
+    let start = *rng.choose(&numbers);
+    let end = packet[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(packet.len(), |len| start + len);
+    return format!(
+        "{}{}{}",
+        &packet[..start],
+        rng.range(0..=10),
+        &packet[end..]
+    );
+}
+
+fn equivalent(a: &Packet, b: &Packet) -> bool {
+    let as_list = |p: &Packet| Entity {
+        e_type: EntityType::LIST,
+        list: Some(p.entities.clone()),
+        value: None,
+    };
+    return as_list(a).partial_cmp(&as_list(b)) == Some(cmp::Ordering::Equal);
+}
+
+// No pair holds two equivalent packets and no packet is equivalent to a
+// divider, so both parts have a single well-defined answer
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let dividers = [
+        Packet::from_slice("[[2]]").unwrap(),
+        Packet::from_slice("[[6]]").unwrap(),
+    ];
+    let mut pairs = vec![];
+    while pairs.len() < size.max(1) {
+        let p1 = generate_packet(rng);
+        let p2 = match rng.chance(1, 2) {
+            true => mutate_packet(rng, &p1),
+            false => generate_packet(rng),
+        };
+        let packets = [
+            Packet::from_slice(&p1).unwrap(),
+            Packet::from_slice(&p2).unwrap(),
+        ];
+        if equivalent(&packets[0], &packets[1])
+            || packets
+                .iter()
+                .any(|p| dividers.iter().any(|d| equivalent(p, d)))
+        {
+            continue;
+        }
+        pairs.push(format!("{}\n{}\n", p1, p2));
+    }
+    return pairs.join("\n");
+}
@@ -0,0 +1,29 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_13::part1(EXAMPLE), Ok(13));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_13::part2(EXAMPLE), Ok(140));
+}
+
+#[test]
+fn crlf_and_extra_blank_lines() {
+    let messy = EXAMPLE
+        .replace('\n', "\r\n")
+        .replace("\r\n\r\n", "\r\n\t\r\n\r\n");
+    assert_eq!(day_13::part1(&messy), Ok(13));
+}
+
+#[test]
+fn pair_errors_name_their_group() {
+    let error = day_13::part1("[1]\n[2]\n\n[3]\n\n[4]\n[5]\n").unwrap_err();
+    assert_eq!(error.line, 4);
+    assert_eq!(error.message, "missing second packet of pair in group 2");
+
+    let error = day_13::part1("[1]\n[2]\n[3]\n").unwrap_err();
+    assert_eq!((error.line, error.text.as_str()), (3, "[3]"));
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use aoc_common::Rng;
+
+#[derive(Clone)]
+enum Value {
+    Int(u32),
+    List(Vec<Value>),
+}
+
+fn parse(s: &[u8], pos: &mut usize) -> Value {
+    if s[*pos] == b'[' {
+        *pos += 1;
+        let mut items = vec![];
+        while s[*pos] != b']' {
+            if s[*pos] == b',' {
+                *pos += 1;
+            }
+            items.push(parse(s, pos));
+        }
+        *pos += 1;
+        return Value::List(items);
+    }
+    let start = *pos;
+    while s[*pos].is_ascii_digit() {
+        *pos += 1;
+    }
+    Value::Int(
+        std::str::from_utf8(&s[start..*pos])
+            .unwrap()
+            .parse()
+            .unwrap(),
+    )
+}
+
+fn packet(s: &str) -> Value {
+    parse(s.as_bytes(), &mut 0)
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::List(a), Value::List(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| compare(a, b))
+            .find(|o| o.is_ne())
+            .unwrap_or(a.len().cmp(&b.len())),
+        (Value::Int(a), list) => compare(&Value::List(vec![Value::Int(*a)]), list),
+        (list, Value::Int(b)) => compare(list, &Value::List(vec![Value::Int(*b)])),
+    }
+}
+
+fn depth(s: &str) -> usize {
+    let mut depth = 0;
+    let mut deepest = 0;
+    for c in s.chars() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ => (),
+        }
+        deepest = deepest.max(depth);
+    }
+    deepest
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    let mut deepest = 0;
+    for seed in 0..50 {
+        let input = day_13::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let packets = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(packet)
+            .collect::<Vec<Value>>();
+        deepest = deepest.max(depth(&input));
+
+        let part1 = packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| compare(&pair[0], &pair[1]) == Ordering::Less)
+            .map(|(i, _)| i + 1)
+            .sum();
+        let position = |divider: &str| {
+            let divider = packet(divider);
+            packets
+                .iter()
+                .filter(|p| compare(p, &divider) == Ordering::Less)
+                .count()
+                + 1
+        };
+        let part2 = position("[[2]]") * (position("[[6]]") + 1);
+        assert_eq!(day_13::part1(&input), Ok(part1), "seed {}", seed);
+        assert_eq!(day_13::part2(&input), Ok(part2), "seed {}", seed);
+    }
+    assert!(deepest >= 20);
+}
@@ -0,0 +1,314 @@
+use std::fmt;
+
+use aoc_common::{source_lines, Grid, ParseError, Point2, Pos, Rng, SourceLine, Vec2, Visualize};
+
+pub struct Params {
+    pub source_x: u32,
+    pub floor_offset: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            source_x: 500,
+            floor_offset: 2,
+        }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "source_x" => self.source_x = value.parse().map_err(|_| invalid())?,
+            "floor_offset" => self.floor_offset = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Line {
+    pub points: Vec<Point2<u32>>,
+    x_low: u32,
+    x_high: u32,
+    y_high: u32,
+}
+
+impl Line {
+    fn new(points: Vec<Point2<u32>>, x_low: u32, x_high: u32, y_high: u32) -> Self {
+        Self {
+            points,
+            x_low,
+            x_high,
+            y_high,
+        }
+    }
+
+    pub fn parse(line: &SourceLine) -> Result<Self, ParseError> {
+        let mut x_low = u32::MAX;
+        let mut x_high = 0;
+        let mut y_high = 0;
+        let mut points = vec![];
+
+        for x in line.text.split(" -> ") {
+            let p = Point2::<u32>::parse(line, x)?;
+            if p.x < x_low {
+                x_low = p.x;
+            }
+            if p.x > x_high {
+                x_high = p.x;
+            }
+            if p.y > y_high {
+                y_high = p.y;
+            }
+            points.push(p);
+        }
+        return Ok(Self::new(points, x_low, x_high, y_high));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    AIR,
+    ROCK,
+    SAND,
+    SOURCE,
+}
+
+impl Tile {
+    fn symbol(&self) -> char {
+        match self {
+            Tile::AIR => '.',
+            Tile::ROCK => '#',
+            Tile::SAND => 'o',
+            Tile::SOURCE => '+',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+enum Step {
+    FALL(Pos),
+    REST,
+    ABYSS,
+}
+
+pub struct Cave {
+    map: Grid<Tile>,
+    source: Pos,
+    x_offset: u32,
+    sand_amount: u32,
+}
+
+impl Cave {
+    pub fn from_lines(source: Point2<u32>, lines: &[Line], floor_offset: u32) -> Self {
+        let y_high = lines
+            .iter()
+            .map(|l| l.y_high)
+            .max()
+            .unwrap_or(0)
+            .max(source.y);
+        let floor = y_high + floor_offset;
+        // Sand spreads at most one column per row, so this is as wide as the pile can get
+        let x_low = lines
+            .iter()
+            .map(|l| l.x_low)
+            .min()
+            .unwrap_or(source.x)
+            .min(source.x.saturating_sub(floor + 1));
+        let x_high = lines
+            .iter()
+            .map(|l| l.x_high)
+            .max()
+            .unwrap_or(source.x)
+            .max(source.x + floor + 1);
+
+        let mut cave = Self {
+            map: Grid::new(
+                (x_high - x_low + 1) as usize,
+                (floor + 1) as usize,
+                Tile::AIR,
+            ),
+            source: Point2::new((source.x - x_low) as usize, source.y as usize),
+            x_offset: x_low,
+            sand_amount: 0,
+        };
+        cave.draw_lines(lines);
+        cave.map[cave.source] = Tile::SOURCE;
+        return cave;
+    }
+
+    fn draw_lines(&mut self, lines: &[Line]) {
+        lines
+            .iter()
+            .flat_map(|l| l.points.iter().zip(l.points.iter().skip(1)))
+            .for_each(|(p, p_next)| {
+                for x in p.x.min(p_next.x)..=p.x.max(p_next.x) {
+                    for y in p.y.min(p_next.y)..=p.y.max(p_next.y) {
+                        self.map[Point2::new((x - self.x_offset) as usize, y as usize)] =
+                            Tile::ROCK;
+                    }
+                }
+            });
+    }
+
+    pub fn add_floor(&mut self) {
+        let floor = self.map.height() - 1;
+        for x in 0..self.map.width() {
+            self.map[Point2::new(x, floor)] = Tile::ROCK;
+        }
+    }
+
+    fn next_step(&self, pos: Pos) -> Step {
+        for dx in [0, -1, 1] {
+            match self
+                .map
+                .offset(pos, Vec2::new(dx, 1))
+                .map(|p| (p, self.map[p]))
+            {
+                None => return Step::ABYSS,
+                Some((p, Tile::AIR)) => return Step::FALL(p),
+                Some(_) => (),
+            }
+        }
+        return Step::REST;
+    }
+
+    pub fn simulate_sand_from_source(&mut self) -> u32 {
+        return self.simulate(&mut |_| ());
+    }
+
+    fn simulate(&mut self, on_rest: &mut dyn FnMut(&Cave)) -> u32 {
+        if self.map[self.source] == Tile::SAND {
+            return self.sand_amount;
+        }
+        // Each grain follows the path of the previous one up to where that one came to rest
+        let mut path = vec![self.source];
+        while let Some(&pos) = path.last() {
+            match self.next_step(pos) {
+                Step::FALL(next) => path.push(next),
+                Step::REST => {
+                    self.map[pos] = Tile::SAND;
+                    self.sand_amount += 1;
+                    path.pop();
+                    on_rest(self);
+                }
+                Step::ABYSS => break,
+            }
+        }
+        return self.sand_amount;
+    }
+
+    pub fn render_map(&self) -> String {
+        let columns = (0..self.map.width())
+            .filter(|&x| {
+                self.map
+                    .column(x)
+                    .take(self.map.height() - 1)
+                    .any(|&t| t != Tile::AIR)
+            })
+            .collect::<Vec<usize>>();
+        let x_low = columns.first().copied().unwrap_or(0);
+        let x_high = columns.last().copied().unwrap_or(0);
+        let width = (self.map.height() - 1).to_string().len() + 1;
+
+        self.map
+            .rows()
+            .enumerate()
+            .map(|(i, row)| {
+                let line = row[x_low..=x_high]
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<String>();
+                format!("{:width$} {}", i, line, width = width)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl Visualize for Cave {
+    fn visualize(&mut self, frame: &mut dyn FnMut(&Grid<char>)) {
+        let mut draw = |cave: &Cave| frame(&cave.map.map(Tile::symbol));
+        draw(self);
+        self.simulate(&mut draw);
+        self.add_floor();
+        draw(self);
+        self.simulate(&mut draw);
+    }
+}
+
+pub fn build_cave(input: &str, params: &Params) -> Result<Cave, ParseError> {
+    let lines = source_lines(input)
+        .map(|line| Line::parse(&line))
+        .collect::<Result<Vec<Line>, ParseError>>()?;
+    return Ok(Cave::from_lines(
+        Point2::new(params.source_x, 0),
+        &lines,
+        params.floor_offset,
+    ));
+}
+
+pub fn fill_cave_with_floor(cave: &mut Cave) -> u32 {
+    cave.simulate_sand_from_source();
+    cave.add_floor();
+    return cave.simulate_sand_from_source();
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<u32, ParseError> {
+    Ok(build_cave(input, params)?.simulate_sand_from_source())
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<u32, ParseError> {
+    Ok(fill_cave_with_floor(&mut build_cave(input, params)?))
+}
+
+pub fn render_filled_cave(input: &str) -> Result<String, ParseError> {
+    let params = Params::default();
+    let mut cave = build_cave(input, &params)?;
+    fill_cave_with_floor(&mut cave);
+    Ok(cave.render_map())
+}
+
+// Rock paths scattered below the default sand source, never touching row 0
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let source_x = Params::default().source_x as i64;
+    let spread = 5 + size as i64;
+    let depth = 5 + size as i64 / 2;
+    (0..size.max(1))
+        .map(|_| {
+            let mut p = Point2::new(source_x + rng.range(-spread..=spread), rng.range(1..=depth));
+            let mut points = vec![p];
+            let horizontal = rng.chance(1, 2);
+            for i in 0..rng.range(1..=4) {
+                let step = rng.range(1..=6) * if rng.chance(1, 2) { 1 } else { -1 };
+                if (i % 2 == 0) == horizontal {
+                    p.x = (p.x + step).max(1);
+                } else {
+                    p.y = (p.y + step).max(1);
+                }
+                points.push(p);
+            }
+            points
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>()
+                .join(" -> ")
+                + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+fn naive_rocks(input: &str) -> HashSet<(i32, i32)> {
+    let mut rocks = HashSet::new();
+    for line in input.lines() {
+        let points = line
+            .split(" -> ")
+            .map(|p| {
+                let (x, y) = p.split_once(',').unwrap();
+                (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap())
+            })
+            .collect::<Vec<(i32, i32)>>();
+        for w in points.windows(2) {
+            for x in w[0].0.min(w[1].0)..=w[0].0.max(w[1].0) {
+                for y in w[0].1.min(w[1].1)..=w[0].1.max(w[1].1) {
+                    rocks.insert((x, y));
+                }
+            }
+        }
+    }
+    rocks
+}
+
+// Drops one grain at a time until one falls past the floor or the source is buried
+fn naive_sand(input: &str, with_floor: bool) -> u32 {
+    let mut blocked = naive_rocks(input);
+    let bottom = blocked.iter().map(|p| p.1).max().unwrap_or(0);
+    let mut grains = 0;
+    while !blocked.contains(&(500, 0)) {
+        let (mut x, mut y) = (500, 0);
+        loop {
+            if with_floor && y == bottom + 1 {
+                break;
+            }
+            if !with_floor && y > bottom {
+                return grains;
+            }
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&nx| !blocked.contains(&(nx, y + 1)))
+            {
+                Some(nx) => (x, y) = (nx, y + 1),
+                None => break,
+            }
+        }
+        blocked.insert((x, y));
+        grains += 1;
+    }
+    grains
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_14::generate(&mut Rng::new(seed), 1 + seed as usize % 20);
+        assert_eq!(
+            day_14::part1(&input),
+            Ok(naive_sand(&input, false)),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_14::part2(&input),
+            Ok(naive_sand(&input, true)),
+            "seed {}",
+            seed
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::{io::BufRead, ops::RangeInclusive};
+
+use aoc_common::{
+    for_each_line, source_lines, Dir4, IntervalSet, ParseError, Point2, Rng, SourceLine,
+};
+
+pub struct Params {
+    pub row: isize,
+    pub search_bound: isize,
+    pub freq_multiplier: isize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            search_bound: 4000000,
+            freq_multiplier: 4000000,
+        }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "row" => self.row = value.parse().map_err(|_| invalid())?,
+            "search_bound" => self.search_bound = value.parse().map_err(|_| invalid())?,
+            "freq_multiplier" => self.freq_multiplier = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Marker {
+    pub sensor: Point2<isize>,
+    pub beacon: Point2<isize>,
+    pub d: isize,
+}
+
+impl Marker {
+    fn from_str(line: &SourceLine) -> Result<Self, ParseError> {
+        let (sensor, beacon) = line.split_once(line.text, ":")?;
+        let sensor = Point2::parse(line, line.strip_prefix(sensor, "Sensor at ")?)?;
+        let beacon = Point2::parse(line, line.strip_prefix(beacon, " closest beacon is at ")?)?;
+        return Ok(Self {
+            sensor,
+            beacon,
+            d: sensor.manhattan(&beacon),
+        });
+    }
+
+    pub fn coverage(&self, row: isize) -> RangeInclusive<isize> {
+        let t = self.d - (self.sensor.y - row).abs();
+        return self.sensor.x - t..=self.sensor.x + t;
+    }
+}
+
+pub fn calculate_ranges(m: &[Marker], row: isize) -> IntervalSet<isize> {
+    return m.iter().map(|marker| marker.coverage(row)).collect();
+}
+
+pub fn parse_markers(input: &str) -> Result<Vec<Marker>, ParseError> {
+    source_lines(input)
+        .map(|line| Marker::from_str(&line))
+        .collect::<Result<Vec<Marker>, ParseError>>()
+}
+
+pub fn part1(input: &str) -> Result<isize, ParseError> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<isize, ParseError> {
+    let markers = parse_markers(input)?;
+    let beacons = markers
+        .iter()
+        .filter(|m| m.beacon.y == params.row)
+        .map(|m| m.beacon.x..=m.beacon.x)
+        .collect::<IntervalSet<isize>>();
+    Ok(calculate_ranges(&markers, params.row)
+        .difference(&beacons)
+        .covered_len())
+}
+
+pub fn part2(input: &str) -> Result<isize, ParseError> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<isize, ParseError> {
+    tuning_frequency(&parse_markers(input)?, params)
+}
+
+fn tuning_frequency(markers: &[Marker], params: &Params) -> Result<isize, ParseError> {
+    let bounds = IntervalSet::from(0..=params.search_bound);
+
+    (0..=params.search_bound)
+        .filter_map(|y| {
+            let gaps = bounds.difference(&calculate_ranges(markers, y));
+            let gap = gaps.iter().next().map(|gap| (*gap.start(), y));
+            gap
+        })
+        .map(|(x, y)| x * params.freq_multiplier + y)
+        .next()
+        .ok_or_else(|| {
+            ParseError::whole_input(format!(
+                "no distress beacon position found within 0..={}",
+                params.search_bound
+            ))
+        })
+}
+
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(isize, isize), ParseError> {
+    solve_stream_with(reader, &Params::default())
+}
+
+// Part 1 is folded in as the input is read. Part 2 has to scan every row
+// against every sensor, so the parsed markers (but not the text) are kept.
+pub fn solve_stream_with<R: BufRead>(
+    reader: R,
+    params: &Params,
+) -> Result<(isize, isize), ParseError> {
+    let mut markers = vec![];
+    let mut covered = IntervalSet::new();
+    let mut beacons = IntervalSet::new();
+
+    for_each_line(reader, |line| {
+        let marker = Marker::from_str(&line)?;
+        covered.insert(marker.coverage(params.row));
+        if marker.beacon.y == params.row {
+            beacons.insert(marker.beacon.x..=marker.beacon.x);
+        }
+        markers.push(marker);
+        return Ok(());
+    })?;
+
+    return Ok((
+        covered.difference(&beacons).covered_len(),
+        tuning_frequency(&markers, params)?,
+    ));
+}
+
+pub const MAX_GENERATED_BOUND: usize = 2000;
+
+struct Coverage {
+    bound: isize,
+    covered: Vec<bool>,
+    markers: Vec<(Point2<isize>, Point2<isize>)>,
+}
+
+impl Coverage {
+    fn add(&mut self, rng: &mut Rng, sensor: Point2<isize>, d: isize) {
+        let dx = rng.range(-d as i64..=d as i64) as isize;
+        let dy = (d - dx.abs()) * if rng.chance(1, 2) { 1 } else { -1 };
+        self.markers
+            .push((sensor, Point2::new(sensor.x + dx, sensor.y + dy)));
+
+        for y in (sensor.y - d).max(0)..=(sensor.y + d).min(self.bound) {
+            let t = d - (sensor.y - y).abs();
+            for x in (sensor.x - t).max(0)..=(sensor.x + t).min(self.bound) {
+                self.covered[(y * (self.bound + 1) + x) as usize] = true;
+            }
+        }
+    }
+}
+
+// The search area is 0..=size in both directions, so solve the result with
+// search_bound set to the same size. Sensors are added until the distress
+// beacon is the only position left uncovered.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<String, String> {
+    if size > MAX_GENERATED_BOUND {
+        return Err(format!(
+            "Size {} is too large, the search area is at most {}",
+            size, MAX_GENERATED_BOUND
+        ));
+    }
+    let bound = size.max(1) as isize;
+    let random_point = |rng: &mut Rng, low: isize, high: isize| {
+        Point2::new(
+            rng.range(low as i64..=high as i64) as isize,
+            rng.range(low as i64..=high as i64) as isize,
+        )
+    };
+    let distress = random_point(rng, 0, bound);
+    let mut coverage = Coverage {
+        bound,
+        covered: vec![false; ((bound + 1) * (bound + 1)) as usize],
+        markers: vec![],
+    };
+
+    for _ in 0..rng.range(0..=4) {
+        let sensor = random_point(rng, -bound / 4, bound + bound / 4);
+        if sensor != distress {
+            let d = rng.range(0..=sensor.manhattan(&distress) as i64 - 1) as isize;
+            coverage.add(rng, sensor, d);
+        }
+    }
+    if rng.chance(1, 2) {
+        let sensor = distress + Dir4::ALL[rng.index(4)].delta();
+        coverage.add(rng, sensor, 0);
+    }
+
+    let cells = coverage.covered.len();
+    let distress_index = (distress.y * (bound + 1) + distress.x) as usize;
+    loop {
+        let start = rng.index(cells);
+        let Some(index) = (0..cells)
+            .map(|offset| (start + offset) % cells)
+            .find(|&i| i != distress_index && !coverage.covered[i])
+        else {
+            break;
+        };
+        let sensor = Point2::new(index as isize % (bound + 1), index as isize / (bound + 1));
+        coverage.add(rng, sensor, sensor.manhattan(&distress) - 1);
+    }
+
+    rng.shuffle(&mut coverage.markers);
+    Ok(coverage
+        .markers
+        .iter()
+        .map(|(s, b)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s.x, s.y, b.x, b.y
+            )
+        })
+        .collect())
+}
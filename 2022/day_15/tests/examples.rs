@@ -0,0 +1,36 @@
+use day_15::Params;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+fn example_params() -> Params {
+    Params {
+        row: 10,
+        search_bound: 20,
+        ..Params::default()
+    }
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_15::part1_with(EXAMPLE, &example_params()), Ok(26));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_15::part2_with(EXAMPLE, &example_params()), Ok(56000011));
+}
+
+#[test]
+fn no_beacon_position_is_an_error() {
+    // The sensor at 2,2 covers every position up to 5 away from it
+    let params = Params {
+        search_bound: 4,
+        ..Params::default()
+    };
+    let input = "Sensor at x=2, y=2: closest beacon is at x=-3, y=2\n";
+    let error = day_15::part2_with(input, &params).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "no distress beacon position found within 0..=4"
+    );
+}
@@ -0,0 +1,69 @@
+use aoc_common::Rng;
+use day_15::Params;
+
+fn naive_markers(input: &str) -> Vec<[isize; 4]> {
+    input
+        .lines()
+        .map(|line| {
+            let numbers = line
+                .split(|c: char| c != '-' && !c.is_ascii_digit())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect::<Vec<isize>>();
+            [numbers[0], numbers[1], numbers[2], numbers[3]]
+        })
+        .collect()
+}
+
+fn covered(markers: &[[isize; 4]], x: isize, y: isize) -> bool {
+    markers.iter().any(|&[sx, sy, bx, by]| {
+        (sx - x).abs() + (sy - y).abs() <= (sx - bx).abs() + (sy - by).abs()
+    })
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    let mut saw_zero_radius = false;
+    for seed in 0..30 {
+        let bound = 10 + seed as usize * 2;
+        let input = day_15::generate(&mut Rng::new(seed), bound).unwrap();
+        let markers = naive_markers(&input);
+        saw_zero_radius |= markers.iter().any(|m| m[0] == m[2] && m[1] == m[3]);
+        let params = Params {
+            row: bound as isize / 2,
+            search_bound: bound as isize,
+            ..Params::default()
+        };
+
+        let reach = bound as isize * 3;
+        let part1 = (-reach..=bound as isize + reach)
+            .filter(|&x| covered(&markers, x, params.row))
+            .filter(|&x| !markers.iter().any(|m| m[2] == x && m[3] == params.row))
+            .count() as isize;
+        let uncovered = (0..=params.search_bound)
+            .flat_map(|y| (0..=params.search_bound).map(move |x| (x, y)))
+            .filter(|&(x, y)| !covered(&markers, x, y))
+            .collect::<Vec<(isize, isize)>>();
+        assert_eq!(uncovered.len(), 1, "seed {}", seed);
+        let part2 = uncovered[0].0 * params.freq_multiplier + uncovered[0].1;
+
+        assert_eq!(
+            day_15::part1_with(&input, &params),
+            Ok(part1),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_15::part2_with(&input, &params),
+            Ok(part2),
+            "seed {}",
+            seed
+        );
+    }
+    assert!(saw_zero_radius);
+}
+
+#[test]
+fn rejects_oversized_search_areas() {
+    assert!(day_15::generate(&mut Rng::new(0), day_15::MAX_GENERATED_BOUND + 1).is_err());
+}
@@ -0,0 +1,46 @@
+use aoc_common::Rng;
+use day_15::Params;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn matches_in_memory_solution() {
+    let mut cases = vec![(
+        EXAMPLE.to_string(),
+        Params {
+            row: 10,
+            search_bound: 20,
+            ..Params::default()
+        },
+    )];
+    for seed in 0..10 {
+        let bound = 10 + seed as usize * 5;
+        cases.push((
+            day_15::generate(&mut Rng::new(seed), bound).unwrap(),
+            Params {
+                row: bound as isize / 2,
+                search_bound: bound as isize,
+                ..Params::default()
+            },
+        ));
+    }
+
+    for (input, params) in cases {
+        assert_eq!(
+            day_15::solve_stream_with(input.as_bytes(), &params),
+            Ok((
+                day_15::part1_with(&input, &params).unwrap(),
+                day_15::part2_with(&input, &params).unwrap()
+            ))
+        );
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9 y=16\n";
+    assert_eq!(
+        day_15::solve_stream(input.as_bytes()).unwrap_err(),
+        day_15::part1(input).unwrap_err()
+    );
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use aoc_common::{floyd_warshall, source_lines, Graph, ParseError, Rng, SourceLine};
+
+pub struct Params {
+    pub minutes: isize,
+    pub elephant_minutes: isize,
+    pub start_valve: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            minutes: 30,
+            elephant_minutes: 26,
+            start_valve: String::from("AA"),
+        }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "minutes" => self.minutes = value.parse().map_err(|_| invalid())?,
+            "elephant_minutes" => self.elephant_minutes = value.parse().map_err(|_| invalid())?,
+            "start_valve" => self.start_valve = value.to_string(),
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Valve {
+    pub name: String,
+    pub flow_rate: isize,
+    pub tunnels: Vec<String>,
+}
+
+impl Valve {
+    pub fn new(name: String, flow_rate: isize, tunnels: Vec<String>) -> Self {
+        Self {
+            name,
+            flow_rate,
+            tunnels,
+        }
+    }
+
+    fn from_str(line: &SourceLine) -> Result<Self, ParseError> {
+        let (valve, tunnels) = line.split_once(line.text, "; ")?;
+        let (name, flow_rate) =
+            line.split_once(line.strip_prefix(valve, "Valve ")?, " has flow rate=")?;
+        return Ok(Self::new(
+            name.to_string(),
+            line.parse::<isize>(flow_rate)?,
+            tunnels
+                .trim_matches(|c: char| c.is_lowercase() || c.is_whitespace())
+                .split(", ")
+                .map(|s| s.to_string())
+                .collect(),
+        ));
+    }
+}
+
+pub struct Cave {
+    pub minutes: isize,
+    valves: Vec<Valve>,
+    v_index: HashMap<String, usize>,
+    nz_frate_v_index: HashMap<String, usize>,
+    distance_field: HashMap<String, HashMap<String, isize>>,
+    start_valve: String,
+}
+
+impl Cave {
+    pub fn new(minutes: isize, valves: Vec<Valve>, start_valve: String) -> Self {
+        Self {
+            minutes,
+            valves,
+            v_index: HashMap::new(),
+            nz_frate_v_index: HashMap::new(),
+            start_valve,
+            distance_field: HashMap::new(),
+        }
+    }
+
+    pub fn calculate_lookup_tables(&mut self) -> &mut Self {
+        self.valves
+            .iter()
+            .filter(|v| v.flow_rate > 0)
+            .enumerate()
+            .for_each(|(i, v)| {
+                self.nz_frate_v_index.insert(v.name.clone(), i);
+            });
+
+        self.valves.iter().enumerate().for_each(|(i, v)| {
+            self.v_index.insert(v.name.clone(), i);
+        });
+
+        return self;
+    }
+
+    pub fn calculate_distance_field(&mut self) -> &mut Self {
+        let nodes = (0..self.valves.len()).collect::<Vec<usize>>();
+        let distances = floyd_warshall(&*self, &nodes);
+
+        for (from, v) in self.valves.iter().enumerate() {
+            if v.name != self.start_valve && v.flow_rate == 0 {
+                continue;
+            }
+            let distance_map = self
+                .valves
+                .iter()
+                .enumerate()
+                .filter(|(to, t)| *to != from && t.flow_rate > 0)
+                .filter_map(|(to, t)| Some((t.name.clone(), distances[from][to]? as isize)))
+                .collect();
+            self.distance_field.insert(v.name.clone(), distance_map);
+        }
+        return self;
+    }
+
+    fn dfs(
+        &self,
+        t: isize,
+        v: &str,
+        m: usize,
+        cache: &mut HashMap<(isize, String, usize), isize>,
+    ) -> isize {
+        if cache.contains_key(&(t, v.to_string(), m)) {
+            return *cache.get(&(t, v.to_string(), m)).unwrap();
+        }
+        let m_val = self.distance_field[v]
+            .keys()
+            .filter(|i| m & (1 << self.nz_frate_v_index[*i]) == 0)
+            .map(|i| {
+                let new_time: isize = t - self.distance_field[v][i] - 1;
+                if new_time <= 0 {
+                    return 0;
+                }
+                return self.dfs(new_time, i, m | (1 << self.nz_frate_v_index[i]), cache)
+                    + self.valves[self.v_index[i]].flow_rate * new_time;
+            })
+            .max()
+            .unwrap_or(0);
+        cache.insert((t, v.to_string(), m), m_val);
+        return m_val;
+    }
+
+    pub fn calculate_max_released_pressure(&mut self) -> isize {
+        let cache = &mut HashMap::new();
+        return self.dfs(self.minutes, &self.start_valve, 0, cache);
+    }
+
+    pub fn calculate_max_released_pressure_with_elephant(&mut self) -> isize {
+        let cache = &mut HashMap::new();
+        let b: usize = (0..self.nz_frate_v_index.len()).fold(0, |b, i| b | 1 << i);
+        return (0..b.div_ceil(2))
+            .map(|i| {
+                self.dfs(self.minutes, &self.start_valve, i, cache)
+                    + self.dfs(self.minutes, &self.start_valve, b ^ i, cache)
+            })
+            .max()
+            .unwrap_or(0);
+    }
+}
+
+impl Graph for Cave {
+    type Node = usize;
+
+    fn neighbours(&self, valve: &usize) -> impl Iterator<Item = usize> {
+        self.valves[*valve]
+            .tunnels
+            .iter()
+            .map(|name| self.v_index[name])
+    }
+}
+
+pub fn build_cave(input: &str, minutes: isize, start_valve: &str) -> Result<Cave, ParseError> {
+    let lines = source_lines(input).collect::<Vec<SourceLine>>();
+    let valves = lines
+        .iter()
+        .map(Valve::from_str)
+        .collect::<Result<Vec<Valve>, ParseError>>()?;
+
+    for (i, (line, valve)) in lines.iter().zip(&valves).enumerate() {
+        if valves[..i].iter().any(|v| v.name == valve.name) {
+            return Err(line.error(&valve.name, "valve is already defined"));
+        }
+        if let Some(tunnel) = valve
+            .tunnels
+            .iter()
+            .find(|t| !valves.iter().any(|v| &v.name == *t))
+        {
+            return Err(line.error(tunnel, "tunnel leads to unknown valve"));
+        }
+    }
+
+    if !valves.iter().any(|v| v.name == start_valve) {
+        return Err(ParseError::whole_input(format!(
+            "start valve '{}' is not in the input",
+            start_valve
+        )));
+    }
+
+    // Opened valves are tracked as bits of a usize
+    let flow_valves = valves.iter().filter(|v| v.flow_rate > 0).count();
+    if flow_valves > usize::BITS as usize {
+        return Err(ParseError::whole_input(format!(
+            "{} valves have a flow rate, at most {} are supported",
+            flow_valves,
+            usize::BITS
+        )));
+    }
+
+    let mut cave = Cave::new(minutes, valves, start_valve.to_string());
+    cave.calculate_lookup_tables().calculate_distance_field();
+    return Ok(cave);
+}
+
+pub fn part1(input: &str) -> Result<isize, ParseError> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<isize, ParseError> {
+    Ok(build_cave(input, params.minutes, &params.start_valve)?.calculate_max_released_pressure())
+}
+
+pub fn part2(input: &str) -> Result<isize, ParseError> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<isize, ParseError> {
+    Ok(
+        build_cave(input, params.elephant_minutes, &params.start_valve)?
+            .calculate_max_released_pressure_with_elephant(),
+    )
+}
+
+// A connected tunnel network of `size` valves where only a handful have a
+// flow rate, like the puzzle input; the start valve never has one
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let start_valve = Params::default().start_valve;
+    let count = size.clamp(2, 26 * 26);
+    let mut names = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+        .filter(|name| *name != start_valve)
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut names);
+    names.insert(0, start_valve);
+    names.truncate(count);
+
+    let mut tunnels = vec![vec![]; count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for i in 1..count {
+        connect(i, rng.index(i));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.index(count), rng.index(count));
+    }
+
+    let mut flow_rates = vec![0; count];
+    for _ in 0..(count / 8).clamp(1, 8) {
+        flow_rates[1 + rng.index(count - 1)] = rng.range(1..=25);
+    }
+
+    let mut lines = (0..count)
+        .map(|i| {
+            let targets = tunnels[i]
+                .iter()
+                .map(|&t| names[t].as_str())
+                .collect::<Vec<&str>>();
+            let plural = if targets.len() == 1 { "" } else { "s" };
+            format!(
+                "Valve {} has flow rate={}; tunnel{} lead{} to valve{} {}\n",
+                names[i],
+                flow_rates[i],
+                plural,
+                if plural.is_empty() { "s" } else { "" },
+                plural,
+                targets.join(", ")
+            )
+        })
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut lines);
+    return lines.concat();
+}
@@ -0,0 +1,30 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_16::part1(EXAMPLE), Ok(1651));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_16::part2(EXAMPLE), Ok(1707));
+}
+
+#[test]
+fn unknown_start_valve_is_an_error() {
+    let mut params = day_16::Params::default();
+    params.set("start_valve", "ZZ").unwrap();
+    let error = day_16::part1_with(EXAMPLE, &params).unwrap_err();
+    assert_eq!(error.to_string(), "start valve 'ZZ' is not in the input");
+    assert_eq!(day_16::part2_with(EXAMPLE, &params), Err(error));
+}
+
+#[test]
+fn duplicate_valve_is_an_error() {
+    let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                 Valve BB has flow rate=5; tunnel leads to valve AA\n\
+                 Valve BB has flow rate=9; tunnel leads to valve AA\n";
+    let error = day_16::part1(input).unwrap_err();
+    assert_eq!((error.line, error.column), (3, 7));
+    assert_eq!(error.message, "valve is already defined");
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use aoc_common::Rng;
+
+struct NaiveCave {
+    flow: Vec<isize>,
+    tunnels: Vec<Vec<usize>>,
+    useful: Vec<usize>,
+    start: usize,
+}
+
+fn naive_cave(input: &str) -> NaiveCave {
+    let lines = input
+        .lines()
+        .map(|line| {
+            let words = line.split(' ').collect::<Vec<&str>>();
+            let flow = words[4]
+                .trim_start_matches("rate=")
+                .trim_end_matches(';')
+                .parse::<isize>()
+                .unwrap();
+            let tunnels = words[9..]
+                .iter()
+                .map(|t| t.trim_end_matches(','))
+                .collect::<Vec<&str>>();
+            (words[1], flow, tunnels)
+        })
+        .collect::<Vec<(&str, isize, Vec<&str>)>>();
+    let index = lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| (l.0, i))
+        .collect::<HashMap<&str, usize>>();
+    NaiveCave {
+        flow: lines.iter().map(|l| l.1).collect(),
+        tunnels: lines
+            .iter()
+            .map(|l| l.2.iter().map(|t| index[t]).collect())
+            .collect(),
+        useful: (0..lines.len()).filter(|&i| lines[i].1 > 0).collect(),
+        start: index["AA"],
+    }
+}
+
+// Minute by minute over every (valve, opened set) state, without any
+// distance compression. best[m] is the most pressure released by opening
+// exactly the valves in m.
+fn naive_best_per_set(cave: &NaiveCave, minutes: isize) -> Vec<isize> {
+    let sets = 1 << cave.useful.len();
+    let bit = |valve: usize| cave.useful.iter().position(|&u| u == valve);
+    let mut states = vec![vec![None; sets]; cave.flow.len()];
+    states[cave.start][0] = Some(0);
+    for minute in 1..=minutes {
+        let mut next = vec![vec![None; sets]; cave.flow.len()];
+        for (valve, valve_states) in states.iter().enumerate() {
+            for (set, released) in valve_states.iter().enumerate() {
+                let Some(released) = *released else {
+                    continue;
+                };
+                let mut update = |v: usize, s: usize, r: isize| {
+                    let cell: &mut Option<isize> = &mut next[v][s];
+                    *cell = Some(cell.map_or(r, |c| c.max(r)));
+                };
+                update(valve, set, released);
+                for &t in &cave.tunnels[valve] {
+                    update(t, set, released);
+                }
+                if let Some(b) = bit(valve) {
+                    if set & (1 << b) == 0 {
+                        update(
+                            valve,
+                            set | 1 << b,
+                            released + cave.flow[valve] * (minutes - minute),
+                        );
+                    }
+                }
+            }
+        }
+        states = next;
+    }
+
+    let mut best = vec![0; sets];
+    for valve_states in &states {
+        for (set, released) in valve_states.iter().enumerate() {
+            best[set] = best[set].max(released.unwrap_or(0));
+        }
+    }
+    best
+}
+
+fn naive_part1(cave: &NaiveCave) -> isize {
+    naive_best_per_set(cave, 30).into_iter().max().unwrap()
+}
+
+fn naive_part2(cave: &NaiveCave) -> isize {
+    let best = naive_best_per_set(cave, 26);
+    (0..best.len())
+        .flat_map(|a| {
+            (0..best.len())
+                .filter(move |b| a & b == 0)
+                .map(move |b| (a, b))
+        })
+        .map(|(a, b)| best[a] + best[b])
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..20 {
+        let input = day_16::generate(&mut Rng::new(seed), 2 + seed as usize * 2);
+        let cave = naive_cave(&input);
+        assert_eq!(
+            day_16::part1(&input),
+            Ok(naive_part1(&cave)),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_16::part2(&input),
+            Ok(naive_part2(&cave)),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn many_valves_with_a_few_flow_rates() {
+    for seed in 0..3 {
+        let input = day_16::generate(&mut Rng::new(seed), 70);
+        assert_eq!(input.lines().count(), 70);
+        let cave = naive_cave(&input);
+        assert_eq!(
+            day_16::part1(&input),
+            Ok(naive_part1(&cave)),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+// A corridor from AA past `count` valves that all have a flow rate
+fn corridor(count: usize) -> String {
+    let names = (0..=count)
+        .map(|i| {
+            format!(
+                "{}{}",
+                (b'A' + (i / 26) as u8) as char,
+                (b'A' + (i % 26) as u8) as char
+            )
+        })
+        .collect::<Vec<String>>();
+    (0..=count)
+        .map(|i| {
+            let tunnels = [i.checked_sub(1), Some(i + 1).filter(|&n| n <= count)]
+                .into_iter()
+                .flatten()
+                .map(|n| names[n].as_str())
+                .collect::<Vec<&str>>();
+            format!(
+                "Valve {} has flow rate={}; tunnels lead to valves {}\n",
+                names[i],
+                if i == 0 { 0 } else { 1 },
+                tunnels.join(", ")
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn more_valves_than_bits_in_a_mask() {
+    let input = corridor(usize::BITS as usize + 1);
+    let error = day_16::part1(&input).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "65 valves have a flow rate, at most 64 are supported"
+    );
+    assert_eq!(day_16::part2(&input), Err(error));
+
+    // Walking on and opening the next valve takes two minutes, so within ten
+    // minutes only the first four ever release anything
+    let input = corridor(usize::BITS as usize);
+    let mut params = day_16::Params::default();
+    params.set("minutes", "10").unwrap();
+    assert_eq!(day_16::part1_with(&input, &params), Ok(8 + 6 + 4 + 2));
+}
@@ -0,0 +1,402 @@
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, source_lines, ParseError, Rng, SourceLine};
+
+pub mod optimize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    WIN,
+    LOSS,
+    DRAW,
+}
+
+impl GameOutcome {
+    fn parse(name: &str) -> Option<GameOutcome> {
+        return match name {
+            "win" => Some(GameOutcome::WIN),
+            "loss" => Some(GameOutcome::LOSS),
+            "draw" => Some(GameOutcome::DRAW),
+            _ => None,
+        };
+    }
+
+    fn name(&self) -> &'static str {
+        return match self {
+            GameOutcome::WIN => "win",
+            GameOutcome::LOSS => "loss",
+            GameOutcome::DRAW => "draw",
+        };
+    }
+}
+
+// The rule table as configured, with items still referred to by name. Every
+// list is whitespace-separated, e.g. `beats = "rock>scissors paper>rock"` or
+// `response = "X:rock Y:paper Z:scissors"`.
+pub struct Params {
+    pub items: Vec<String>,
+    pub points: Vec<u32>,
+    // None for a cyclic tournament: each item beats the (n - 1) / 2 items listed
+    // before it, wrapping around
+    pub beats: Option<Vec<(String, String)>>,
+    pub loss_bonus: u32,
+    pub draw_bonus: u32,
+    pub win_bonus: u32,
+    pub opponent: Vec<(String, String)>,
+    pub response: Vec<(String, String)>,
+    pub outcomes: Vec<(String, String)>,
+    // Report the best letter assignments instead of scoring the configured ones
+    pub optimize: bool,
+}
+
+fn names(value: &str) -> Vec<String> {
+    value.split_whitespace().map(String::from).collect()
+}
+
+fn pairs(value: &str, separator: char) -> Option<Vec<(String, String)>> {
+    value
+        .split_whitespace()
+        .map(|pair| {
+            let (a, b) = pair.split_once(separator)?;
+            Some((a.to_string(), b.to_string()))
+        })
+        .collect()
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            items: names("rock paper scissors"),
+            points: vec![1, 2, 3],
+            beats: None,
+            loss_bonus: 0,
+            draw_bonus: 3,
+            win_bonus: 6,
+            opponent: pairs("A:rock B:paper C:scissors", ':').unwrap(),
+            response: pairs("X:rock Y:paper Z:scissors", ':').unwrap(),
+            outcomes: pairs("X:loss Y:draw Z:win", ':').unwrap(),
+            optimize: false,
+        }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "items" => self.items = names(value),
+            "points" => {
+                self.points = value
+                    .split_whitespace()
+                    .map(|p| p.parse())
+                    .collect::<Result<Vec<u32>, _>>()
+                    .map_err(|_| invalid())?
+            }
+            "beats" if value.trim() == "cyclic" => self.beats = None,
+            "beats" => self.beats = Some(pairs(value, '>').ok_or_else(invalid)?),
+            "loss_bonus" => self.loss_bonus = value.parse().map_err(|_| invalid())?,
+            "draw_bonus" => self.draw_bonus = value.parse().map_err(|_| invalid())?,
+            "win_bonus" => self.win_bonus = value.parse().map_err(|_| invalid())?,
+            "opponent" => self.opponent = pairs(value, ':').ok_or_else(invalid)?,
+            "response" => self.response = pairs(value, ':').ok_or_else(invalid)?,
+            "outcomes" => self.outcomes = pairs(value, ':').ok_or_else(invalid)?,
+            "optimize" => self.optimize = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+
+    // Resolves the names once all parameters are known, since they refer to each other
+    pub fn rules(&self) -> Result<Rules, String> {
+        let n = self.items.len();
+        if n == 0 {
+            return Err(String::from("The game needs at least one item"));
+        }
+        if let Some((i, item)) = self
+            .items
+            .iter()
+            .enumerate()
+            .find(|(i, item)| self.items[..*i].contains(item))
+        {
+            return Err(format!("Item '{}' is listed twice (item {})", item, i + 1));
+        }
+        if self.points.len() != n {
+            return Err(format!(
+                "Expected {} points, one per item, but got {}",
+                n,
+                self.points.len()
+            ));
+        }
+        let item = |name: &str| {
+            self.items
+                .iter()
+                .position(|item| item == name)
+                .ok_or_else(|| format!("Unknown item '{}'", name))
+        };
+
+        let mut beats = vec![vec![false; n]; n];
+        match &self.beats {
+            None => {
+                for (a, row) in beats.iter_mut().enumerate() {
+                    for offset in 1..=(n - 1) / 2 {
+                        row[(a + n - offset) % n] = true;
+                    }
+                }
+            }
+            Some(relations) => {
+                for (winner, loser) in relations {
+                    let (a, b) = (item(winner)?, item(loser)?);
+                    if a == b || beats[b][a] {
+                        return Err(format!(
+                            "'{}' cannot beat '{}' in the same game",
+                            winner, loser
+                        ));
+                    }
+                    beats[a][b] = true;
+                }
+            }
+        }
+
+        let letters = |map: &[(String, String)], what: &str| {
+            for (i, (letter, _)) in map.iter().enumerate() {
+                if map[..i].iter().any(|(l, _)| l == letter) {
+                    return Err(format!("Letter '{}' is mapped twice in {}", letter, what));
+                }
+            }
+            Ok(())
+        };
+        letters(&self.opponent, "opponent")?;
+        letters(&self.response, "response")?;
+        letters(&self.outcomes, "outcomes")?;
+
+        let to_items = |map: &[(String, String)]| {
+            map.iter()
+                .map(|(letter, name)| Ok((letter.clone(), item(name)?)))
+                .collect::<Result<Vec<(String, usize)>, String>>()
+        };
+        return Ok(Rules {
+            items: self.items.clone(),
+            points: self.points.clone(),
+            beats,
+            loss_bonus: self.loss_bonus,
+            draw_bonus: self.draw_bonus,
+            win_bonus: self.win_bonus,
+            opponent: to_items(&self.opponent)?,
+            response: to_items(&self.response)?,
+            outcomes: self
+                .outcomes
+                .iter()
+                .map(|(letter, name)| {
+                    GameOutcome::parse(name)
+                        .map(|outcome| (letter.clone(), outcome))
+                        .ok_or_else(|| format!("Unknown outcome '{}'", name))
+                })
+                .collect::<Result<Vec<(String, GameOutcome)>, String>>()?,
+        });
+    }
+}
+
+// Items are indices into `items`
+pub struct Rules {
+    pub items: Vec<String>,
+    pub points: Vec<u32>,
+    // beats[a][b] is true when item a beats item b; pairs where neither beats
+    // the other are a draw
+    pub beats: Vec<Vec<bool>>,
+    pub loss_bonus: u32,
+    pub draw_bonus: u32,
+    pub win_bonus: u32,
+    pub opponent: Vec<(String, usize)>,
+    pub response: Vec<(String, usize)>,
+    pub outcomes: Vec<(String, GameOutcome)>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Params::default().rules().unwrap()
+    }
+}
+
+fn lookup<T: Copy>(map: &[(String, T)], letter: &str) -> Option<T> {
+    map.iter()
+        .find(|(l, _)| l == letter)
+        .map(|(_, value)| *value)
+}
+
+impl Rules {
+    pub fn opponent_item(&self, line: &SourceLine, input: &str) -> Result<usize, ParseError> {
+        lookup(&self.opponent, input).ok_or_else(|| line.error(input, "invalid game item"))
+    }
+
+    pub fn response_item(&self, line: &SourceLine, input: &str) -> Result<usize, ParseError> {
+        lookup(&self.response, input).ok_or_else(|| line.error(input, "invalid game item"))
+    }
+
+    pub fn from_instruction(
+        &self,
+        line: &SourceLine,
+        p1_val: usize,
+        instruction: &str,
+    ) -> Result<usize, ParseError> {
+        let outcome = lookup(&self.outcomes, instruction)
+            .ok_or_else(|| line.error(instruction, "invalid instruction"))?;
+        self.get_response(outcome, p1_val).ok_or_else(|| {
+            let message = format!(
+                "no item gives a {} against {}",
+                outcome.name(),
+                self.items[p1_val]
+            );
+            line.error(instruction, message)
+        })
+    }
+
+    // The outcome for the second player
+    pub fn outcome(&self, p1_val: usize, p2_val: usize) -> GameOutcome {
+        if self.beats[p2_val][p1_val] {
+            return GameOutcome::WIN;
+        }
+        if self.beats[p1_val][p2_val] {
+            return GameOutcome::LOSS;
+        }
+        return GameOutcome::DRAW;
+    }
+
+    pub fn bonus(&self, outcome: GameOutcome) -> u32 {
+        return match outcome {
+            GameOutcome::WIN => self.win_bonus,
+            GameOutcome::LOSS => self.loss_bonus,
+            GameOutcome::DRAW => self.draw_bonus,
+        };
+    }
+
+    // When several items give the outcome, the one worth the most points (the
+    // first listed on a tie) is played
+    pub fn get_response(&self, result: GameOutcome, p1_val: usize) -> Option<usize> {
+        return (0..self.items.len())
+            .filter(|&item| self.outcome(p1_val, item) == result)
+            .rev()
+            .max_by_key(|&item| self.points[item]);
+    }
+}
+
+pub struct Game {
+    pub p_1_game_item: usize,
+    pub p_2_game_item: usize,
+    pub p_1_points: u32,
+    pub p_2_points: u32,
+}
+
+impl Game {
+    pub fn parse(
+        line: &SourceLine,
+        rules: &Rules,
+        is_instruction: bool,
+    ) -> Result<Game, ParseError> {
+        let mut items = line.text.split_whitespace();
+        let p_1 = items
+            .next()
+            .ok_or_else(|| line.error(line.end(), "missing opponent item"))?;
+        let p_2 = items
+            .next()
+            .ok_or_else(|| line.error(line.end(), "missing response"))?;
+        let p_1_game_item = rules.opponent_item(line, p_1)?;
+
+        return Ok(Game {
+            p_1_game_item,
+            p_2_game_item: if is_instruction {
+                rules.from_instruction(line, p_1_game_item, p_2)?
+            } else {
+                rules.response_item(line, p_2)?
+            },
+            p_1_points: 0,
+            p_2_points: 0,
+        });
+    }
+
+    pub fn play(&mut self, rules: &Rules) {
+        let outcome = rules.outcome(self.p_1_game_item, self.p_2_game_item);
+        let p_1_outcome = rules.outcome(self.p_2_game_item, self.p_1_game_item);
+        self.p_1_points = rules.points[self.p_1_game_item] + rules.bonus(p_1_outcome);
+        self.p_2_points = rules.points[self.p_2_game_item] + rules.bonus(outcome);
+    }
+}
+
+pub fn parse_games(
+    input: &str,
+    rules: &Rules,
+    is_instruction: bool,
+) -> Result<Vec<Game>, ParseError> {
+    let mut games: Vec<Game> = vec![];
+
+    for line in source_lines(input) {
+        games.push(Game::parse(&line, rules, is_instruction)?);
+    }
+    return Ok(games);
+}
+
+pub fn play_games(
+    input: &str,
+    rules: &Rules,
+    is_instruction: bool,
+) -> Result<Vec<Game>, ParseError> {
+    let mut games = parse_games(input, rules, is_instruction)?;
+
+    for game in games.iter_mut() {
+        game.play(rules);
+    }
+    return Ok(games);
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    part1_with(input, &Rules::default())
+}
+
+pub fn part1_with(input: &str, rules: &Rules) -> Result<u32, ParseError> {
+    let games_from_str = play_games(input, rules, false)?;
+    Ok(games_from_str.iter().map(|x| x.p_2_points).sum::<u32>())
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    part2_with(input, &Rules::default())
+}
+
+pub fn part2_with(input: &str, rules: &Rules) -> Result<u32, ParseError> {
+    let games_from_instruction = play_games(input, rules, true)?;
+    Ok(games_from_instruction
+        .iter()
+        .map(|x| x.p_2_points)
+        .sum::<u32>())
+}
+
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(u64, u64), ParseError> {
+    solve_stream_with(reader, &Rules::default())
+}
+
+// Both parts in one pass; the totals are u64 so very large inputs cannot overflow them
+pub fn solve_stream_with<R: BufRead>(reader: R, rules: &Rules) -> Result<(u64, u64), ParseError> {
+    let mut totals = (0u64, 0u64);
+
+    for_each_line(reader, |line| {
+        let mut from_str = Game::parse(&line, rules, false)?;
+        let mut from_instruction = Game::parse(&line, rules, true)?;
+        from_str.play(rules);
+        from_instruction.play(rules);
+        totals.0 += from_str.p_2_points as u64;
+        totals.1 += from_instruction.p_2_points as u64;
+        return Ok(());
+    })?;
+
+    return Ok(totals);
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&["A", "B", "C"]),
+                rng.choose(&["X", "Y", "Z"])
+            )
+        })
+        .collect()
+}
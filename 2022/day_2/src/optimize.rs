@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+
+use aoc_common::{source_lines, ParseError};
+
+use crate::{GameOutcome, Rules};
+
+const OUTCOMES: [GameOutcome; 3] = [GameOutcome::LOSS, GameOutcome::DRAW, GameOutcome::WIN];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    ITEMS,
+    OUTCOMES,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub interpretation: Interpretation,
+    // Letter and the item or outcome it stands for, in the configured letter order
+    pub letters: Vec<(String, String)>,
+    pub score: u64,
+    // Whether this is how the rules read the column (part 1 for items, part 2 for outcomes)
+    pub configured: bool,
+}
+
+// How often each second-column letter is played against each opponent item
+fn tally(input: &str, rules: &Rules, letters: &[&str]) -> Result<Vec<Vec<u64>>, ParseError> {
+    let mut counts = vec![vec![0; letters.len()]; rules.items.len()];
+
+    for line in source_lines(input) {
+        let mut tokens = line.text.split_whitespace();
+        let p_1 = tokens
+            .next()
+            .ok_or_else(|| line.error(line.end(), "missing opponent item"))?;
+        let p_2 = tokens
+            .next()
+            .ok_or_else(|| line.error(line.end(), "missing response"))?;
+        let letter = letters
+            .iter()
+            .position(|&letter| letter == p_2)
+            .ok_or_else(|| line.error(p_2, "invalid second column"))?;
+        counts[rules.opponent_item(&line, p_1)?][letter] += 1;
+    }
+    return Ok(counts);
+}
+
+// Every ordered choice of k distinct values out of 0..n, in lexicographic
+// order, produced one at a time since there are n! / (n - k)! of them
+fn arrangements(n: usize, k: usize) -> impl Iterator<Item = Vec<usize>> {
+    let first = (k <= n).then(|| (0..k).collect::<Vec<usize>>());
+    return std::iter::successors(first, move |current| next_arrangement(current, n));
+}
+
+// Bumps the last position that can still grow and refills the ones after it
+// with the smallest unused values
+fn next_arrangement(current: &[usize], n: usize) -> Option<Vec<usize>> {
+    for i in (0..current.len()).rev() {
+        let used = &current[..i];
+        if let Some(value) = (current[i] + 1..n).find(|v| !used.contains(v)) {
+            let mut next = used.to_vec();
+            next.push(value);
+            let rest = (0..n)
+                .filter(|v| !next.contains(v))
+                .take(current.len() - i - 1)
+                .collect::<Vec<usize>>();
+            next.extend(rest);
+            return Some(next);
+        }
+    }
+    return None;
+}
+
+// The second player's total for one letter assignment, None when a letter that
+// is played asks for an outcome no item can give
+fn score(
+    rules: &Rules,
+    counts: &[Vec<u64>],
+    response: impl Fn(usize, usize) -> Option<usize>,
+) -> Option<u64> {
+    let mut total = 0;
+    for (p_1, row) in counts.iter().enumerate() {
+        for (letter, &count) in row.iter().enumerate().filter(|(_, &count)| count > 0) {
+            let p_2 = response(p_1, letter)?;
+            let points = rules.points[p_2] + rules.bonus(rules.outcome(p_1, p_2));
+            total += count * points as u64;
+        }
+    }
+    return Some(total);
+}
+
+pub fn item_mappings(input: &str, rules: &Rules) -> Result<Vec<Mapping>, ParseError> {
+    let letters = rules
+        .response
+        .iter()
+        .map(|(l, _)| l.as_str())
+        .collect::<Vec<&str>>();
+    let counts = tally(input, rules, &letters)?;
+    if letters.len() > rules.items.len() {
+        return Err(ParseError::whole_input(format!(
+            "{} response letters cannot stand for different ones of {} items",
+            letters.len(),
+            rules.items.len()
+        )));
+    }
+
+    return Ok(arrangements(rules.items.len(), letters.len())
+        .filter_map(|items| {
+            Some(Mapping {
+                interpretation: Interpretation::ITEMS,
+                letters: letters
+                    .iter()
+                    .zip(&items)
+                    .map(|(l, &item)| (l.to_string(), rules.items[item].clone()))
+                    .collect(),
+                score: score(rules, &counts, |_, letter| Some(items[letter]))?,
+                configured: rules.response.iter().zip(&items).all(|((_, a), b)| a == b),
+            })
+        })
+        .collect());
+}
+
+pub fn outcome_mappings(input: &str, rules: &Rules) -> Result<Vec<Mapping>, ParseError> {
+    let letters = rules
+        .outcomes
+        .iter()
+        .map(|(l, _)| l.as_str())
+        .collect::<Vec<&str>>();
+    let counts = tally(input, rules, &letters)?;
+    if letters.len() > OUTCOMES.len() {
+        return Err(ParseError::whole_input(format!(
+            "{} outcome letters cannot stand for different ones of {} outcomes",
+            letters.len(),
+            OUTCOMES.len()
+        )));
+    }
+
+    let mappings = arrangements(OUTCOMES.len(), letters.len())
+        .filter_map(|outcomes| {
+            let outcome = |letter: usize| OUTCOMES[outcomes[letter]];
+            Some(Mapping {
+                interpretation: Interpretation::OUTCOMES,
+                letters: letters
+                    .iter()
+                    .enumerate()
+                    .map(|(i, l)| (l.to_string(), outcome(i).name().to_string()))
+                    .collect(),
+                score: score(rules, &counts, |p_1, letter| {
+                    rules.get_response(outcome(letter), p_1)
+                })?,
+                configured: (0..letters.len()).all(|i| rules.outcomes[i].1 == outcome(i)),
+            })
+        })
+        .collect::<Vec<Mapping>>();
+    if mappings.is_empty() {
+        return Err(ParseError::whole_input(
+            "no assignment of the letters to outcomes can be played under these rules",
+        ));
+    }
+    return Ok(mappings);
+}
+
+// All mappings of both interpretations, best first; on equal scores item
+// mappings come first, each in enumeration order
+pub fn optimize(input: &str, rules: &Rules) -> Result<Vec<Mapping>, ParseError> {
+    let mut mappings = item_mappings(input, rules)?;
+    mappings.extend(outcome_mappings(input, rules)?);
+    mappings.sort_by_key(|m| Reverse(m.score));
+    return Ok(mappings);
+}
+
+// The mappings of one interpretation only, in the same order as `optimize`
+pub fn optimize_as(
+    input: &str,
+    rules: &Rules,
+    interpretation: Interpretation,
+) -> Result<Vec<Mapping>, ParseError> {
+    let mut mappings = match interpretation {
+        Interpretation::ITEMS => item_mappings(input, rules)?,
+        Interpretation::OUTCOMES => outcome_mappings(input, rules)?,
+    };
+    mappings.sort_by_key(|m| Reverse(m.score));
+    return Ok(mappings);
+}
+
+pub fn to_text(mappings: &[Mapping]) -> String {
+    let describe = |m: &Mapping| {
+        m.letters
+            .iter()
+            .map(|(letter, meaning)| format!("{}={}", letter, meaning))
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+    let width = mappings
+        .iter()
+        .map(|m| describe(m).len())
+        .max()
+        .unwrap_or(0);
+    let score_width = mappings
+        .iter()
+        .map(|m| m.score.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = vec![];
+    for (interpretation, title) in [
+        (Interpretation::ITEMS, "Second column as items"),
+        (Interpretation::OUTCOMES, "Second column as outcomes"),
+    ] {
+        if !mappings.iter().any(|m| m.interpretation == interpretation) {
+            continue;
+        }
+        lines.push(format!("{}:", title));
+        for m in mappings
+            .iter()
+            .filter(|m| m.interpretation == interpretation)
+        {
+            let note = match (m.configured, interpretation) {
+                (false, _) => "",
+                (true, Interpretation::ITEMS) => "  (part 1)",
+                (true, Interpretation::OUTCOMES) => "  (part 2)",
+            };
+            lines.push(format!(
+                "  {:<width$}  {:>score_width$}{}",
+                describe(m),
+                m.score,
+                note,
+                width = width,
+                score_width = score_width
+            ));
+        }
+    }
+    if let Some(best) = mappings.iter().rev().max_by_key(|m| m.score) {
+        lines.push(String::new());
+        lines.push(format!(
+            "Best: {} as {} with {}",
+            describe(best),
+            match best.interpretation {
+                Interpretation::ITEMS => "items",
+                Interpretation::OUTCOMES => "outcomes",
+            },
+            best.score
+        ));
+    }
+    return lines.join("\n");
+}
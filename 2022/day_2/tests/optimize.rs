@@ -0,0 +1,156 @@
+use aoc_common::Rng;
+use day_2::{
+    optimize::{self, Interpretation, Mapping},
+    Params,
+};
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+// The same mapping expressed as a letter map parameter
+fn letter_map(mapping: &Mapping) -> String {
+    mapping
+        .letters
+        .iter()
+        .map(|(letter, meaning)| format!("{}:{}", letter, meaning))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[test]
+fn example_mappings() {
+    let rules = Params::default().rules().unwrap();
+    let mappings = optimize::optimize(EXAMPLE, &rules).unwrap();
+    assert_eq!(mappings.len(), 12);
+    assert!(mappings.windows(2).all(|w| w[0].score >= w[1].score));
+
+    let configured = mappings
+        .iter()
+        .filter(|m| m.configured)
+        .map(|m| (m.interpretation, m.score))
+        .collect::<Vec<(Interpretation, u64)>>();
+    assert_eq!(
+        configured,
+        vec![(Interpretation::ITEMS, 15), (Interpretation::OUTCOMES, 12)]
+    );
+
+    // A Y, B X, C Z: reading X as scissors and Y as paper wins every round
+    let best = &mappings[0];
+    assert_eq!(best.interpretation, Interpretation::ITEMS);
+    assert_eq!(letter_map(best), "X:scissors Y:paper Z:rock");
+    assert_eq!(best.score, 8 + 9 + 7);
+}
+
+#[test]
+fn scores_match_solving_with_the_mapping() {
+    for seed in 0..10 {
+        let input = day_2::generate(&mut Rng::new(seed), 1 + seed as usize * 7);
+        let rules = Params::default().rules().unwrap();
+        for mapping in optimize::optimize(&input, &rules).unwrap() {
+            let mut params = Params::default();
+            let expected = match mapping.interpretation {
+                Interpretation::ITEMS => {
+                    params.set("response", &letter_map(&mapping)).unwrap();
+                    day_2::part1_with(&input, &params.rules().unwrap())
+                }
+                Interpretation::OUTCOMES => {
+                    params.set("outcomes", &letter_map(&mapping)).unwrap();
+                    day_2::part2_with(&input, &params.rules().unwrap())
+                }
+            };
+            assert_eq!(Ok(mapping.score), expected.map(u64::from), "seed {}", seed);
+        }
+    }
+}
+
+#[test]
+fn mappings_follow_the_rule_table() {
+    let mut params = Params::default();
+    params
+        .set("items", "rock paper scissors lizard spock")
+        .unwrap();
+    params.set("points", "1 2 3 4 5").unwrap();
+    let rules = params.rules().unwrap();
+    // Three response letters over five items, three outcome letters over three outcomes
+    assert_eq!(optimize::item_mappings(EXAMPLE, &rules).unwrap().len(), 60);
+    assert_eq!(
+        optimize::outcome_mappings(EXAMPLE, &rules).unwrap().len(),
+        6
+    );
+}
+
+#[test]
+fn unknown_letters_are_reported() {
+    let rules = Params::default().rules().unwrap();
+    let error = optimize::optimize("A X\nB Q\n", &rules).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.message, "invalid second column");
+}
+
+#[test]
+fn text_report_marks_the_configured_and_best_mappings() {
+    let rules = Params::default().rules().unwrap();
+    let text = optimize::to_text(&optimize::optimize(EXAMPLE, &rules).unwrap());
+    let lines = text.lines().collect::<Vec<&str>>();
+    assert_eq!(lines[0], "Second column as items:");
+    assert_eq!(lines[1], "  X=scissors Y=paper Z=rock  24");
+    assert!(lines.contains(&"  X=rock Y=paper Z=scissors  15  (part 1)"));
+    assert!(lines.contains(&"  X=loss Y=draw Z=win        12  (part 2)"));
+    assert_eq!(
+        lines.last(),
+        Some(&"Best: X=scissors Y=paper Z=rock as items with 24")
+    );
+}
+
+#[test]
+fn impossible_mappings_are_errors() {
+    let mut params = Params::default();
+    params.set("items", "rock paper").unwrap();
+    params.set("points", "1 2").unwrap();
+    params.set("beats", "paper>rock").unwrap();
+    params.set("opponent", "A:rock B:paper").unwrap();
+    params.set("response", "X:rock Y:paper Z:paper").unwrap();
+    let rules = params.rules().unwrap();
+
+    // Three letters for two items, and nothing ever beats paper
+    let error = optimize::item_mappings("A X\n", &rules).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "3 response letters cannot stand for different ones of 2 items"
+    );
+    let error = optimize::outcome_mappings("B X\nB Y\nB Z\n", &rules).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "no assignment of the letters to outcomes can be played under these rules"
+    );
+
+    let mut params = Params::default();
+    params
+        .set("outcomes", "W:loss X:loss Y:draw Z:win")
+        .unwrap();
+    let error = optimize::outcome_mappings("A W\n", &params.rules().unwrap()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "4 outcome letters cannot stand for different ones of 3 outcomes"
+    );
+}
+
+#[test]
+fn few_letters_over_many_items() {
+    let mut params = Params::default();
+    let items = (1..=12).map(|i| format!("i{}", i)).collect::<Vec<String>>();
+    params.set("items", &items.join(" ")).unwrap();
+    params
+        .set(
+            "points",
+            &(1..=12)
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>()
+                .join(" "),
+        )
+        .unwrap();
+    params.set("opponent", "A:i1").unwrap();
+    params.set("response", "X:i1 Y:i2").unwrap();
+    let rules = params.rules().unwrap();
+    // Two letters over twelve items
+    assert_eq!(optimize::item_mappings("A X\n", &rules).unwrap().len(), 132);
+}
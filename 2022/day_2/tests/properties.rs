@@ -0,0 +1,23 @@
+use aoc_common::Rng;
+
+// Shapes and outcomes are both cyclic, so the scores follow from arithmetic mod 3
+fn naive_scores(input: &str) -> (u32, u32) {
+    let (mut part1, mut part2) = (0, 0);
+    for line in input.lines() {
+        let bytes = line.as_bytes();
+        let (opponent, column) = ((bytes[0] - b'A') as u32, (bytes[2] - b'X') as u32);
+        part1 += column + 1 + (column + 4 - opponent) % 3 * 3;
+        part2 += (opponent + column + 2) % 3 + 1 + column * 3;
+    }
+    (part1, part2)
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_2::generate(&mut Rng::new(seed), 1 + seed as usize * 3);
+        let (part1, part2) = naive_scores(&input);
+        assert_eq!(day_2::part1(&input), Ok(part1), "seed {}", seed);
+        assert_eq!(day_2::part2(&input), Ok(part2), "seed {}", seed);
+    }
+}
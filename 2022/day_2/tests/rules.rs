@@ -0,0 +1,130 @@
+use day_2::{GameOutcome, Params, Rules};
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+const RPSLS: &str = "A Y\nE X\nC Z\nD X\n";
+
+fn rpsls(beats: &str, items: &str) -> Params {
+    let mut params = Params::default();
+    params.set("items", items).unwrap();
+    params.set("points", "1 2 3 4 5").unwrap();
+    params.set("beats", beats).unwrap();
+    params
+        .set("opponent", "A:rock B:paper C:scissors D:lizard E:spock")
+        .unwrap();
+    params
+        .set("response", "V:rock W:paper X:scissors Y:lizard Z:spock")
+        .unwrap();
+    params
+}
+
+// (winner, loser) names, sorted, for comparing tables with different item orders
+fn relation(rules: &Rules) -> Vec<(String, String)> {
+    let mut pairs = vec![];
+    for (a, row) in rules.beats.iter().enumerate() {
+        for (b, &beats) in row.iter().enumerate() {
+            if beats {
+                pairs.push((rules.items[a].clone(), rules.items[b].clone()));
+            }
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn default_rules_are_rock_paper_scissors() {
+    let rules = Rules::default();
+    assert_eq!(
+        relation(&rules),
+        [
+            ("paper", "rock"),
+            ("rock", "scissors"),
+            ("scissors", "paper")
+        ]
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+    );
+    assert_eq!(rules.outcome(0, 1), GameOutcome::WIN);
+    assert_eq!(rules.outcome(1, 0), GameOutcome::LOSS);
+    assert_eq!(rules.outcome(2, 2), GameOutcome::DRAW);
+    assert_eq!(day_2::part1_with(EXAMPLE, &rules), Ok(15));
+    assert_eq!(day_2::part2_with(EXAMPLE, &rules), Ok(12));
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let explicit = rpsls(
+        "rock>scissors rock>lizard paper>rock paper>spock scissors>paper \
+         scissors>lizard lizard>paper lizard>spock spock>rock spock>scissors",
+        "rock paper scissors lizard spock",
+    )
+    .rules()
+    .unwrap();
+    // Listed in cyclic order, every item beats the two before it
+    let cyclic = rpsls("cyclic", "rock spock paper lizard scissors")
+        .rules()
+        .unwrap();
+    assert_eq!(relation(&explicit), relation(&cyclic));
+
+    assert_eq!(day_2::part1_with(RPSLS, &explicit), Ok(4 + 3 + 11 + 9));
+    // Losing to spock can be done with rock or scissors; scissors scores more
+    assert_eq!(day_2::part2_with(RPSLS, &explicit), Ok(4 + 3 + 11 + 5));
+}
+
+#[test]
+fn cyclic_tournaments_are_balanced() {
+    for n in [3, 5, 7, 9] {
+        let mut params = Params::default();
+        let items = (1..=n).map(|i| i.to_string()).collect::<Vec<String>>();
+        params.set("items", &items.join(" ")).unwrap();
+        params.set("points", &items.join(" ")).unwrap();
+        params.set("opponent", "").unwrap();
+        params.set("response", "").unwrap();
+        let rules = params.rules().unwrap();
+        for row in &rules.beats {
+            assert_eq!(row.iter().filter(|&&b| b).count(), (n - 1) / 2);
+        }
+        for a in 0..n {
+            for b in 0..n {
+                assert!(!(rules.beats[a][b] && rules.beats[b][a]));
+                assert_eq!(a != b, rules.beats[a][b] || rules.beats[b][a], "n {}", n);
+            }
+        }
+    }
+}
+
+#[test]
+fn inconsistent_tables_are_rejected() {
+    let rules = |key: &str, value: &str| {
+        let mut params = Params::default();
+        params.set(key, value).unwrap();
+        params.rules().err()
+    };
+    assert!(rules("points", "1 2").unwrap().contains("one per item"));
+    assert!(rules("items", "rock rock paper")
+        .unwrap()
+        .contains("listed twice"));
+    assert!(rules("beats", "rock>paper paper>rock").is_some());
+    assert!(rules("beats", "rock>rock").is_some());
+    assert_eq!(
+        rules("response", "X:stone").unwrap(),
+        "Unknown item 'stone'"
+    );
+    assert!(rules("outcomes", "X:tie").is_some());
+    assert!(rules("opponent", "A:rock A:paper").is_some());
+
+    let mut params = Params::default();
+    assert!(params.set("beats", "rock-paper").is_err());
+    assert!(params.set("points", "1 two 3").is_err());
+    assert!(params.set("bonus", "1").is_err());
+}
+
+#[test]
+fn unreachable_outcomes_are_reported_at_the_instruction() {
+    // Nothing beats rock in this table
+    let mut params = Params::default();
+    params.set("beats", "rock>scissors rock>paper").unwrap();
+    let rules = params.rules().unwrap();
+    let error = day_2::part2_with("A X\nA Z\n", &rules).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.message, "no item gives a win against rock");
+}
@@ -0,0 +1,150 @@
+use std::{collections::HashSet, io::BufRead, ops::Add};
+
+use aoc_common::{for_each_line, source_lines, ParseError, Rng, SourceLine};
+
+pub struct Backpack {
+    pub contents: String,
+    pub first_comp: String,
+    pub second_comp: String,
+}
+
+impl Backpack {
+    pub fn new(contents: &str) -> Backpack {
+        let slices: (&str, &str) = contents.split_at(contents.len() / 2);
+        return Backpack {
+            contents: contents.to_string(),
+            first_comp: slices.0.to_string(),
+            second_comp: slices.1.to_string(),
+        };
+    }
+
+    pub fn parse(line: &SourceLine) -> Result<Backpack, ParseError> {
+        if let Some((pos, c)) = line
+            .text
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            return Err(line.error(
+                &line.text[pos..pos + c.len_utf8()],
+                "invalid item, expected a-z or A-Z",
+            ));
+        }
+        if !line.text.len().is_multiple_of(2) {
+            return Err(line.error(
+                line.text,
+                "odd number of items, both compartments must hold the same number",
+            ));
+        }
+        return Ok(Backpack::new(line.text));
+    }
+
+    pub fn get_duplicate_items(&self) -> HashSet<u32> {
+        let mut dups: HashSet<u32> = HashSet::new();
+        for c in self.first_comp.chars() {
+            if self.second_comp.contains(c) {
+                dups.insert(c as u32 - (if c.is_uppercase() { 38 } else { 96 }));
+            }
+        }
+        return dups;
+    }
+
+    pub fn find_group_badge_name(&self, bp_2: &Backpack, bp_3: &Backpack) -> Option<u32> {
+        for c in self.contents.chars() {
+            if bp_2.contents.contains(c) && bp_3.contents.contains(c) {
+                return Some(c as u32 - (if c.is_uppercase() { 38 } else { 96 }));
+            }
+        }
+        return None;
+    }
+}
+
+pub fn parse_backpacks(input: &str) -> Result<Vec<Backpack>, ParseError> {
+    let mut bps: Vec<Backpack> = vec![];
+
+    for line in source_lines(input) {
+        let bp = Backpack::parse(&line)?;
+        bps.push(bp);
+    }
+    return Ok(bps);
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let bps = parse_backpacks(input)?;
+    Ok(bps
+        .iter()
+        .flat_map(Backpack::get_duplicate_items)
+        .reduce(Add::add)
+        .unwrap_or(0))
+}
+
+fn no_badge(line: &SourceLine) -> ParseError {
+    line.error(line.text, "no item is shared by this group of three")
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    let bps = parse_backpacks(input)?;
+    let lines = source_lines(input).collect::<Vec<SourceLine>>();
+    let mut sum = 0;
+
+    for (group, lines) in bps.chunks_exact(3).zip(lines.chunks_exact(3)) {
+        sum += group[0]
+            .find_group_badge_name(&group[1], &group[2])
+            .ok_or_else(|| no_badge(&lines[2]))?;
+    }
+
+    Ok(sum)
+}
+
+// Both parts in one pass, holding at most one group of three backpacks at a time
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(u64, u64), ParseError> {
+    let mut totals = (0u64, 0u64);
+    let mut group: Vec<Backpack> = Vec::with_capacity(3);
+
+    for_each_line(reader, |line| {
+        let bp = Backpack::parse(&line)?;
+        totals.0 += bp.get_duplicate_items().iter().sum::<u32>() as u64;
+        group.push(bp);
+        if group.len() == 3 {
+            totals.1 += group[0]
+                .find_group_badge_name(&group[1], &group[2])
+                .ok_or_else(|| no_badge(&line))? as u64;
+            group.clear();
+        }
+        return Ok(());
+    })?;
+
+    return Ok(totals);
+}
+
+fn generate_compartment(rng: &mut Rng, fixed: &[char], pool: &[char], len: usize) -> String {
+    let mut items = fixed.to_vec();
+    while items.len() < len {
+        items.push(*rng.choose(pool));
+    }
+    rng.shuffle(&mut items);
+    return items.into_iter().collect();
+}
+
+// Every backpack shares exactly one item type between its compartments and
+// every group of three shares exactly one badge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.div_ceil(3).max(1) {
+        let mut letters = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        for pool in letters[1..].chunks(17) {
+            let shared = if rng.chance(1, 4) { badge } else { pool[0] };
+            let len = rng.range(2..=16) as usize;
+            let first = if shared == badge {
+                vec![shared]
+            } else {
+                vec![shared, badge]
+            };
+            out += &generate_compartment(rng, &first, &pool[1..9], len);
+            out += &generate_compartment(rng, &[shared], &pool[9..], len);
+            out.push('\n');
+        }
+    }
+    return out;
+}
@@ -0,0 +1,30 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+const EXAMPLE_P2: &str = include_str!("../test_input_p2");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_3::part1(EXAMPLE), Ok(157));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_3::part2(EXAMPLE_P2), Ok(70));
+}
+
+#[test]
+fn odd_number_of_items_is_rejected() {
+    let error = day_3::part1("abcA\nabcab\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (2, 1, "abcab")
+    );
+}
+
+#[test]
+fn group_without_a_badge_is_an_error() {
+    let input = "abab\ncdcd\nefef\n";
+    let error = day_3::part2(input).unwrap_err();
+    assert_eq!(error.line, 3);
+    assert_eq!(error.message, "no item is shared by this group of three");
+    assert_eq!(day_3::solve_stream(input.as_bytes()).unwrap_err(), error);
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+fn priority(c: char) -> u32 {
+    match c {
+        'a'..='z' => c as u32 - 'a' as u32 + 1,
+        _ => c as u32 - 'A' as u32 + 27,
+    }
+}
+
+fn items(s: &str) -> HashSet<char> {
+    s.chars().collect()
+}
+
+fn naive_part1(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            items(first)
+                .intersection(&items(second))
+                .map(|&c| priority(c))
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+fn naive_part2(input: &str) -> u32 {
+    let lines = input.lines().collect::<Vec<&str>>();
+    lines
+        .chunks(3)
+        .map(|group| {
+            let common = group
+                .iter()
+                .map(|l| items(l))
+                .reduce(|a, b| a.intersection(&b).copied().collect())
+                .unwrap();
+            assert_eq!(common.len(), 1, "group must share exactly one badge");
+            common.into_iter().map(priority).sum::<u32>()
+        })
+        .sum()
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_3::generate(&mut Rng::new(seed), 3 + seed as usize);
+        assert_eq!(
+            day_3::part1(&input),
+            Ok(naive_part1(&input)),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_3::part2(&input),
+            Ok(naive_part2(&input)),
+            "seed {}",
+            seed
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_common::Rng;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn matches_in_memory_solution() {
+    let generated =
+        (0..20).map(|seed| day_3::generate(&mut Rng::new(seed), 3 + seed as usize * 10));
+    for input in std::iter::once(EXAMPLE.to_string()).chain(generated) {
+        assert_eq!(
+            day_3::solve_stream(input.as_bytes()),
+            Ok((
+                day_3::part1(&input).unwrap() as u64,
+                day_3::part2(&input).unwrap() as u64
+            ))
+        );
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    let input = "vJrwpWtwJgWr\nhcsFMMfF-FhFp\n";
+    assert_eq!(
+        day_3::solve_stream(input.as_bytes()).unwrap_err(),
+        day_3::part1(input).unwrap_err()
+    );
+}
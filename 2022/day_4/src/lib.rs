@@ -0,0 +1,76 @@
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, source_lines, IntervalSet, ParseError, Rng, SourceLine};
+
+pub fn parse_section(line: &SourceLine, input: &str) -> Result<IntervalSet<u32>, ParseError> {
+    let (start, end) = line.split_once(input, "-")?;
+    let (start, end): (u32, u32) = (line.parse(start)?, line.parse(end)?);
+    if start > end {
+        return Err(line.error(input, "section ends before it starts"));
+    }
+    return Ok(IntervalSet::from(start..=end));
+}
+
+pub fn parse_pair(line: &SourceLine) -> Result<[IntervalSet<u32>; 2], ParseError> {
+    let (first, second) = line.split_once(line.text, ",")?;
+    return Ok([parse_section(line, first)?, parse_section(line, second)?]);
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<[IntervalSet<u32>; 2]>, ParseError> {
+    let mut pairs = vec![];
+
+    for line in source_lines(input) {
+        pairs.push(parse_pair(&line)?);
+    }
+    return Ok(pairs);
+}
+
+// (one section contains the other, the sections overlap)
+fn classify([first, second]: &[IntervalSet<u32>; 2]) -> (bool, bool) {
+    let overlap = first.intersection(second);
+    return (overlap == *first || overlap == *second, !overlap.is_empty());
+}
+
+pub fn count_pairs(input: &str) -> Result<(u32, u32), ParseError> {
+    let mut contains_counter = 0;
+    let mut intersect_counter = 0;
+
+    for pair in parse_pairs(input)? {
+        let (contains, intersects) = classify(&pair);
+        contains_counter += contains as u32;
+        intersect_counter += intersects as u32;
+    }
+    return Ok((contains_counter, intersect_counter));
+}
+
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(u64, u64), ParseError> {
+    let mut counters = (0u64, 0u64);
+
+    for_each_line(reader, |line| {
+        let (contains, intersects) = classify(&parse_pair(&line)?);
+        counters.0 += contains as u64;
+        counters.1 += intersects as u64;
+        return Ok(());
+    })?;
+
+    return Ok(counters);
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Ok(count_pairs(input)?.0)
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    Ok(count_pairs(input)?.1)
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut section = || {
+        let start = rng.range(1..=99);
+        let end = rng.range(start..=99);
+        format!("{}-{}", start, end)
+    };
+    (0..size.max(1))
+        .map(|_| format!("{},{}\n", section(), section()))
+        .collect()
+}
@@ -1,63 +1,25 @@
-use std::fs;
+use std::{fs, process};
 
-enum State {
-    CONTAINS,
-    INTERSECTS,
-    UNRELATED,
-}
+use aoc_common::ParseError;
 
-struct Section {
-    start: u32,
-    end: u32,
-}
+fn solve(input: &str) -> Result<(), ParseError> {
+    // Part 1
+    println!("Part 1: {}", day_4::part1(input)?);
 
-impl Section {
-    fn new(start: u32, end: u32) -> Self {
-        return Self { start, end };
-    }
+    // Part 2
+    println!("Part 2: {}", day_4::part2(input)?);
 
-    fn compare(&self, other: &Section) -> State {
-        return match (self.start, self.end, other.start, other.end) {
-            (s1, e1, s2, e2) if s1 <= s2 && e1 >= e2 => State::CONTAINS,
-            (s1, e1, s2, e2) if s1 >= s2 && e1 <= e2 => State::CONTAINS,
-            (s1, e1, s2, _) if s1 <= s2 && e1 >= s2 => State::INTERSECTS,
-            (s1, e1, _, e2) if s1 <= e2 && e1 >= e2 => State::INTERSECTS,
-            _ => State::UNRELATED,
-        };
-    }
+    Ok(())
 }
 
 fn main() {
-    let input = fs::read_to_string("input_p1").expect("Unable to read file");
-    let input_lines = input.lines();
-    let mut contains_counter = 0;
-    let mut intersect_counter = 0;
-
-    for line in input_lines {
-        let mut pair_vec = vec![];
-        let pairs = line.split(",");
-
-        for pair in pairs {
-            let bounds = pair.split("-");
-            let sec: Section = Section::new(
-                bounds.clone().nth(0).unwrap().parse::<u32>().unwrap(),
-                bounds.clone().nth(1).unwrap().parse::<u32>().unwrap(),
-            );
-            pair_vec.push(sec);
-        }
-        contains_counter += match pair_vec[0].compare(&pair_vec[1]) {
-            State::CONTAINS => 1,
-            _ => 0,
-        };
-        intersect_counter += match pair_vec[0].compare(&pair_vec[1]) {
-            State::INTERSECTS | State::CONTAINS => 1,
-            _ => 0,
-        }
+    let input = fs::read_to_string("input_p1").unwrap_or_else(|e| {
+        eprintln!("Error: Unable to read file input_p1: {}", e);
+        process::exit(1);
+    });
+
+    if let Err(e) = solve(&input) {
+        eprintln!("Error: {}", e.render(&input));
+        process::exit(1);
     }
-
-    // Part 1
-    println!("Part 1: {:?}", contains_counter);
-
-    // Part 2
-    println!("Part 2: {:?}", intersect_counter);
 }
@@ -0,0 +1,11 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_4::part1(EXAMPLE), Ok(2));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_4::part2(EXAMPLE), Ok(4));
+}
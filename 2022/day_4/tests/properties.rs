@@ -0,0 +1,29 @@
+use aoc_common::Rng;
+
+fn naive_counts(input: &str) -> (u32, u32) {
+    let (mut contained, mut overlapping) = (0, 0);
+    for line in input.lines() {
+        let bounds = line
+            .split([',', '-'])
+            .map(|n| n.parse::<u32>().unwrap())
+            .collect::<Vec<u32>>();
+        let (a, b, c, d) = (bounds[0], bounds[1], bounds[2], bounds[3]);
+        if (a <= c && d <= b) || (c <= a && b <= d) {
+            contained += 1;
+        }
+        if a <= d && c <= b {
+            overlapping += 1;
+        }
+    }
+    (contained, overlapping)
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_4::generate(&mut Rng::new(seed), 1 + seed as usize * 4);
+        let (part1, part2) = naive_counts(&input);
+        assert_eq!(day_4::part1(&input), Ok(part1), "seed {}", seed);
+        assert_eq!(day_4::part2(&input), Ok(part2), "seed {}", seed);
+    }
+}
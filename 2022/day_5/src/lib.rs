@@ -0,0 +1,231 @@
+use aoc_common::{groups, ParseError, Rng, SourceLine};
+
+const CRATE_WIDTH: u32 = 3;
+const CRATE_PADDING: u32 = 1;
+
+#[derive(Clone)]
+pub struct Crate {
+    pub id: String,
+}
+
+impl Crate {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
+
+pub fn parse_crate_schema<'a>(
+    stacks: &mut Vec<Vec<Crate>>,
+    stack_count: &mut u32,
+    input_lines: &mut impl Iterator<Item = SourceLine<'a>>,
+) {
+    loop {
+        let line = input_lines.next();
+        if line.is_none() || line.unwrap().text.is_empty() {
+            break;
+        };
+        parse_crate_schema_line(stacks, stack_count, line.unwrap().text);
+    }
+}
+
+fn parse_crate_schema_line(stacks: &mut Vec<Vec<Crate>>, stack_count: &mut u32, line: &str) {
+    let crate_id: &mut String = &mut String::new();
+    let mut current_stack: &mut Vec<Crate> = &mut stacks[0];
+    let mut stack_index: u32 = 0;
+    let mut new_crate: bool = false;
+
+    for (index, c) in line.chars().enumerate() {
+        if (index as u32).is_multiple_of(CRATE_WIDTH + CRATE_PADDING) {
+            stack_index += 1;
+            if stack_count < &mut stack_index {
+                stacks.push(vec![]);
+                *stack_count += 1;
+            }
+            current_stack = &mut stacks[stack_index as usize - 1];
+        }
+
+        match c {
+            '[' => new_crate = true,
+            ']' => {
+                new_crate = false;
+                current_stack.push(Crate::new(crate_id.to_string()));
+                crate_id.clear();
+            }
+            ' ' => continue,
+            _ => {
+                if new_crate {
+                    crate_id.push(c);
+                }
+            }
+        }
+    }
+}
+
+pub struct Move<'a> {
+    pub line: SourceLine<'a>,
+    // The crate count as written, where a move of too many crates is reported
+    pub count: &'a str,
+    pub amount: usize,
+    // 0-based stack indices
+    pub from: usize,
+    pub to: usize,
+}
+
+pub fn parse_instructions<'a>(
+    stack_count: usize,
+    input_lines: &mut impl Iterator<Item = SourceLine<'a>>,
+) -> Result<Vec<Move<'a>>, ParseError> {
+    let mut moves = vec![];
+    for line in input_lines {
+        let instruction: &mut std::str::SplitWhitespace = &mut line.text.split_whitespace();
+        match instruction.next() {
+            Some("move") => moves.push(parse_move(stack_count, line, instruction)?),
+            Some(other) => return Err(line.error(other, "unknown instruction")),
+            None => continue,
+        }
+    }
+    return Ok(moves);
+}
+
+fn stack_index(
+    stack_count: usize,
+    line: &SourceLine,
+    instruction: &mut std::str::SplitWhitespace,
+    keyword: &str,
+) -> Result<usize, ParseError> {
+    let token = instruction
+        .next()
+        .ok_or_else(|| line.error(line.end(), format!("expected '{}'", keyword)))?;
+    if token != keyword {
+        return Err(line.error(token, format!("expected '{}'", keyword)));
+    }
+    let token = instruction
+        .next()
+        .ok_or_else(|| line.error(line.end(), "missing stack number"))?;
+    let index = line.parse::<usize>(token)?;
+    if index == 0 || index > stack_count {
+        return Err(line.error(token, "unknown stack"));
+    }
+    return Ok(index - 1);
+}
+
+fn parse_move<'a>(
+    stack_count: usize,
+    line: SourceLine<'a>,
+    instruction: &mut std::str::SplitWhitespace<'a>,
+) -> Result<Move<'a>, ParseError> {
+    let count = instruction
+        .next()
+        .ok_or_else(|| line.error(line.end(), "missing crate count"))?;
+    return Ok(Move {
+        line,
+        count,
+        amount: line.parse::<usize>(count)?,
+        from: stack_index(stack_count, &line, instruction, "from")?,
+        to: stack_index(stack_count, &line, instruction, "to")?,
+    });
+}
+
+// The starting stacks, top crate first, and the moves of every instruction group
+pub fn parse_procedure(input: &str) -> Result<(Vec<Vec<Crate>>, Vec<Move<'_>>), ParseError> {
+    let mut stacks: Vec<Vec<Crate>> = vec![vec![]];
+    let mut stack_count: u32 = 1;
+    let mut groups = groups(input);
+
+    if let Some(schema) = groups.next() {
+        parse_crate_schema(&mut stacks, &mut stack_count, &mut schema.lines.into_iter());
+    }
+    let mut moves = vec![];
+    for group in groups {
+        moves.extend(
+            parse_instructions(stacks.len(), &mut group.lines.iter().copied())
+                .map_err(|e| group.context(e))?,
+        );
+    }
+    return Ok((stacks, moves));
+}
+
+fn move_crates(stacks: &mut [Vec<Crate>], step: &Move, model: u32) -> Result<(), ParseError> {
+    if step.amount > stacks[step.from].len() {
+        return Err(step.line.error(step.count, "not enough crates on stack"));
+    }
+
+    let move_items: Vec<Crate> = if model == 9000 {
+        stacks[step.from].drain(..step.amount).rev().collect()
+    } else if model == 9001 {
+        stacks[step.from].drain(..step.amount).collect()
+    } else {
+        panic!("Invalid model number")
+    };
+    stacks[step.to].splice(0..0, move_items);
+    return Ok(());
+}
+
+pub fn rearrange_stacks(input: &str, model: u32) -> Result<String, ParseError> {
+    let (mut stacks, moves) = parse_procedure(input)?;
+    for step in &moves {
+        move_crates(&mut stacks, step, model)?;
+    }
+
+    Ok(stacks
+        .iter()
+        .flat_map(|s| s.iter().next())
+        .map(|c| c.id.to_string())
+        .collect::<String>())
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    rearrange_stacks(input, 9000)
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    rearrange_stacks(input, 9001)
+}
+
+// Stacks may start or end up empty; every move is valid when it is reached
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut stacks = (0..rng.range(1..=9))
+        .map(|_| match rng.chance(1, 4) {
+            true => vec![],
+            false => (0..rng.range(1..=8))
+                .map(|_| (b'A' + rng.index(26) as u8) as char)
+                .collect::<Vec<char>>(),
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|s| match s.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => String::from("   "),
+            })
+            .collect::<Vec<String>>();
+        out += &row.join(" ");
+        out.push('\n');
+    }
+    let numbers = (1..=stacks.len())
+        .map(|i| format!(" {} ", i))
+        .collect::<Vec<String>>();
+    out += &numbers.join(" ");
+    out += "\n\n";
+
+    for _ in 0..size {
+        let sources = (0..stacks.len())
+            .filter(|&i| !stacks[i].is_empty())
+            .collect::<Vec<usize>>();
+        if sources.is_empty() || stacks.len() < 2 {
+            break;
+        }
+        let from = *rng.choose(&sources);
+        let to = (from + 1 + rng.index(stacks.len() - 1)) % stacks.len();
+        let amount = rng.range(1..=stacks[from].len() as i64) as usize;
+        let keep = stacks[from].len() - amount;
+        let moved = stacks[from].split_off(keep);
+        stacks[to].extend(moved);
+        out += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+    }
+    return out;
+}
@@ -0,0 +1,35 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_5::part1(EXAMPLE), Ok(String::from("CMZ")));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_5::part2(EXAMPLE), Ok(String::from("MCD")));
+}
+
+#[test]
+fn example_procedure() {
+    let (stacks, moves) = day_5::parse_procedure(EXAMPLE).unwrap();
+    let ids = stacks
+        .iter()
+        .map(|s| s.iter().map(|c| c.id.as_str()).collect::<String>())
+        .collect::<Vec<String>>();
+    assert_eq!(ids, ["NZ", "DCM", "P"]);
+    let steps = moves
+        .iter()
+        .map(|m| (m.amount, m.from, m.to))
+        .collect::<Vec<(usize, usize, usize)>>();
+    assert_eq!(steps, [(1, 1, 0), (3, 0, 2), (2, 1, 0), (1, 0, 1)]);
+}
+
+#[test]
+fn moving_too_many_crates_is_an_error() {
+    let input = "[A]\n 1 \n\nmove 2 from 1 to 1\n";
+    assert!(day_5::parse_procedure(input).is_ok());
+    let error = day_5::part1(input).unwrap_err();
+    assert_eq!((error.line, error.column), (4, 6));
+    assert_eq!(error.message, "not enough crates on stack");
+}
@@ -0,0 +1,60 @@
+use aoc_common::Rng;
+
+// Stacks are read column by column and kept bottom first
+fn naive_rearrange(input: &str, keep_order: bool) -> String {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+    let mut rows = drawing.lines().collect::<Vec<&str>>();
+    let count = rows.pop().unwrap().split_whitespace().count();
+    let mut stacks = vec![vec![]; count];
+    for row in rows.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(1 + 4 * i) {
+                Some(c) if c != ' ' => stack.push(c),
+                _ => (),
+            }
+        }
+    }
+
+    for line in moves.lines() {
+        let words = line.split(' ').collect::<Vec<&str>>();
+        let amount = words[1].parse::<usize>().unwrap();
+        let from = words[3].parse::<usize>().unwrap() - 1;
+        let to = words[5].parse::<usize>().unwrap() - 1;
+        let keep = stacks[from].len() - amount;
+        let mut moved = stacks[from].split_off(keep);
+        if !keep_order {
+            moved.reverse();
+        }
+        stacks[to].extend(moved);
+    }
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    let mut saw_empty_stack = false;
+    for seed in 0..100 {
+        let input = day_5::generate(&mut Rng::new(seed), seed as usize);
+        saw_empty_stack |= input.lines().next().is_some_and(|l| l.contains("    "));
+        assert_eq!(
+            day_5::part1(&input),
+            Ok(naive_rearrange(&input, false)),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_5::part2(&input),
+            Ok(naive_rearrange(&input, true)),
+            "seed {}",
+            seed
+        );
+    }
+    assert!(saw_empty_stack);
+}
+
+#[test]
+fn stacks_without_crates() {
+    let input = "    [A]    \n 1   2   3 \n\nmove 1 from 2 to 3\n";
+    assert_eq!(day_5::part1(input), Ok(String::from("A")));
+    assert_eq!(day_5::part2(" 1   2 \n\n"), Ok(String::new()));
+}
@@ -0,0 +1,209 @@
+use std::{io::BufRead, mem};
+
+use aoc_common::{source_lines, ParseError, Rng, SourceLine};
+
+pub struct DataStream {
+    pub stream: String,
+    pub start_marker_length: u32,
+    pub start_marker_index: u32,
+}
+
+impl DataStream {
+    pub fn new(stream: String) -> Self {
+        return Self {
+            stream,
+            start_marker_length: 0,
+            start_marker_index: 0,
+        };
+    }
+
+    pub fn parse(line: &SourceLine) -> Result<Self, ParseError> {
+        if let Some((pos, c)) = line
+            .text
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            return Err(line.error(
+                &line.text[pos..pos + c.len_utf8()],
+                "invalid character, expected a letter",
+            ));
+        }
+        return Ok(Self::new(line.text.to_string()));
+    }
+
+    pub fn search_marker(&mut self, start_marker_length: u32) {
+        let mut search = MarkerSearch::new(start_marker_length as usize);
+
+        self.start_marker_length = start_marker_length;
+
+        for byte in self.stream.bytes() {
+            search.push(byte);
+            if search.marker_index.is_some() {
+                break;
+            }
+        }
+        self.start_marker_index = search.marker_index.unwrap_or(0) as u32;
+    }
+}
+
+// Finds the end of the first `length` distinct letters one byte at a time, so
+// the stream itself never has to be kept
+pub struct MarkerSearch {
+    length: u64,
+    // One past the last position of each letter, a-z then A-Z
+    last_seen: [u64; 52],
+    window_start: u64,
+    position: u64,
+    pub marker_index: Option<u64>,
+}
+
+impl MarkerSearch {
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0);
+        return Self {
+            length: length as u64,
+            last_seen: [0; 52],
+            window_start: 0,
+            position: 0,
+            marker_index: None,
+        };
+    }
+
+    // `byte` must be an ASCII letter
+    pub fn push(&mut self, byte: u8) {
+        self.position += 1;
+        if self.marker_index.is_some() {
+            return;
+        }
+        let slot = if byte.is_ascii_lowercase() {
+            (byte - b'a') as usize
+        } else {
+            (byte - b'A') as usize + 26
+        };
+        self.window_start = self.window_start.max(self.last_seen[slot]);
+        self.last_seen[slot] = self.position;
+        if self.position - self.window_start >= self.length {
+            self.marker_index = Some(self.position);
+        }
+    }
+}
+
+pub fn parse_data_streams(input: &str) -> Result<Vec<DataStream>, ParseError> {
+    let mut data_streams = vec![];
+
+    for line in source_lines(input) {
+        data_streams.push(DataStream::parse(&line)?);
+    }
+    return Ok(data_streams);
+}
+
+pub fn search_markers(
+    input: &str,
+    start_marker_length: u32,
+) -> Result<Vec<DataStream>, ParseError> {
+    let mut data_streams = parse_data_streams(input)?;
+
+    for data_stream in data_streams.iter_mut() {
+        data_stream.search_marker(start_marker_length);
+    }
+    return Ok(data_streams);
+}
+
+pub fn part1(input: &str) -> Result<Vec<u32>, ParseError> {
+    Ok(search_markers(input, 4)?
+        .iter()
+        .map(|x| x.start_marker_index)
+        .collect::<Vec<u32>>())
+}
+
+pub fn part2(input: &str) -> Result<Vec<u32>, ParseError> {
+    Ok(search_markers(input, 14)?
+        .iter()
+        .map(|x| x.start_marker_index)
+        .collect::<Vec<u32>>())
+}
+
+// Reads bytes rather than lines so that a single multi-gigabyte stream is
+// searched in constant memory. Both parts are found in one pass.
+pub fn solve_stream<R: BufRead>(mut reader: R) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut markers = (vec![], vec![]);
+    let mut searches = (MarkerSearch::new(4), MarkerSearch::new(14));
+    let mut finish_line = |searches: &mut (MarkerSearch, MarkerSearch)| {
+        let (short, long) = mem::replace(searches, (MarkerSearch::new(4), MarkerSearch::new(14)));
+        markers.0.push(short.marker_index.unwrap_or(0));
+        markers.1.push(long.marker_index.unwrap_or(0));
+    };
+    let (mut line, mut column) = (1, 0);
+    let mut carriage_return = false;
+
+    loop {
+        let buffer = reader.fill_buf().map_err(|e| {
+            ParseError::new(line, column + 1, "", format!("unable to read input: {}", e))
+        })?;
+        if buffer.is_empty() {
+            break;
+        }
+        for &byte in buffer {
+            if carriage_return && byte != b'\n' {
+                return Err(ParseError::new(
+                    line,
+                    column,
+                    "\r",
+                    "invalid character, expected a letter",
+                ));
+            }
+            match byte {
+                b'\n' => {
+                    finish_line(&mut searches);
+                    (line, column) = (line + 1, 0);
+                    carriage_return = false;
+                }
+                b'\r' => {
+                    carriage_return = true;
+                    column += 1;
+                }
+                _ if byte.is_ascii_alphabetic() => {
+                    searches.0.push(byte);
+                    searches.1.push(byte);
+                    column += 1;
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        line,
+                        column + 1,
+                        &String::from_utf8_lossy(&[byte]),
+                        "invalid character, expected a letter",
+                    ))
+                }
+            }
+        }
+        let consumed = buffer.len();
+        reader.consume(consumed);
+    }
+    if carriage_return {
+        return Err(ParseError::new(
+            line,
+            column,
+            "\r",
+            "invalid character, expected a letter",
+        ));
+    }
+    if column > 0 {
+        finish_line(&mut searches);
+    }
+
+    return Ok(markers);
+}
+
+// A small alphabet makes markers rare, the distinct tail guarantees both exist
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let alphabet = ('a'..='z').collect::<Vec<char>>();
+    let letters = rng.range(2..=26) as usize;
+    let mut stream = (0..size)
+        .map(|_| alphabet[rng.index(letters)])
+        .collect::<Vec<char>>();
+    let mut tail = alphabet.clone();
+    rng.shuffle(&mut tail);
+    stream.extend(&tail[..14]);
+    return stream.into_iter().collect::<String>() + "\n";
+}
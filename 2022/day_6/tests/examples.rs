@@ -0,0 +1,11 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_6::part1(EXAMPLE), Ok(vec![7, 5, 6, 10, 11]));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_6::part2(EXAMPLE), Ok(vec![19, 23, 23, 29, 26]));
+}
@@ -0,0 +1,33 @@
+use aoc_common::Rng;
+
+fn naive_marker(stream: &str, len: usize) -> u32 {
+    let bytes = stream.as_bytes();
+    (len..=bytes.len())
+        .find(|&end| {
+            let mut window = bytes[end - len..end].to_vec();
+            window.sort_unstable();
+            window.dedup();
+            window.len() == len
+        })
+        .unwrap_or(0) as u32
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_6::generate(&mut Rng::new(seed), seed as usize * 20);
+        let stream = input.trim_end();
+        assert_eq!(
+            day_6::part1(&input),
+            Ok(vec![naive_marker(stream, 4)]),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_6::part2(&input),
+            Ok(vec![naive_marker(stream, 14)]),
+            "seed {}",
+            seed
+        );
+    }
+}
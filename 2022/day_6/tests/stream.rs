@@ -0,0 +1,49 @@
+use std::io::BufReader;
+
+use aoc_common::Rng;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+fn widened(markers: Vec<u32>) -> Vec<u64> {
+    markers.into_iter().map(u64::from).collect()
+}
+
+#[test]
+fn matches_in_memory_solution() {
+    let generated = (0..20).map(|seed| day_6::generate(&mut Rng::new(seed), seed as usize * 20));
+    for input in std::iter::once(EXAMPLE.to_string()).chain(generated) {
+        // A tiny buffer makes windows straddle refills
+        let reader = BufReader::with_capacity(3, input.as_bytes());
+        assert_eq!(
+            day_6::solve_stream(reader),
+            Ok((
+                widened(day_6::part1(&input).unwrap()),
+                widened(day_6::part2(&input).unwrap())
+            ))
+        );
+    }
+}
+
+#[test]
+fn line_endings_and_missing_markers() {
+    assert_eq!(
+        day_6::solve_stream("abcd\r\naab\n\nabcdefghijklmn".as_bytes()),
+        Ok((vec![4, 0, 0, 4], vec![0, 0, 0, 14]))
+    );
+    assert_eq!(
+        day_6::solve_stream("aAbB".as_bytes()),
+        Ok((vec![4], vec![0]))
+    );
+}
+
+#[test]
+fn reports_the_same_errors() {
+    for input in ["abcd\nab1d\n", "abcd\nab\rcd\n", "abc\r"] {
+        assert_eq!(
+            day_6::solve_stream(input.as_bytes()).unwrap_err(),
+            day_6::part1(input).unwrap_err(),
+            "{:?}",
+            input
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use aoc_common::{source_lines, ParseError, Rng};
+
+pub struct Params {
+    pub max_dir_size: u32,
+    pub disk_size: i32,
+    pub required_space: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            max_dir_size: 100000,
+            disk_size: 70000000,
+            required_space: 30000000,
+        }
+    }
+}
+
+impl Params {
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid value '{}' for parameter '{}'", value, key);
+        match key {
+            "max_dir_size" => self.max_dir_size = value.parse().map_err(|_| invalid())?,
+            "disk_size" => self.disk_size = value.parse().map_err(|_| invalid())?,
+            "required_space" => self.required_space = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown parameter '{}'", key)),
+        }
+        Ok(())
+    }
+}
+
+pub fn evaluate_dirs(input: &str) -> Result<HashMap<String, u32>, ParseError> {
+    let mut curr_dir: Vec<&str> = vec!["/"];
+    let mut dirs_raw: HashMap<String, u32> = HashMap::new();
+
+    for source_line in source_lines(input) {
+        let line = source_line.text;
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with("$ cd ") {
+            let dir: &str = line.trim_start_matches("$ cd ");
+            if dir == ".." {
+                curr_dir.pop();
+                continue;
+            } else if dir == "/" {
+                curr_dir.clear();
+            }
+            curr_dir.push(dir);
+            dirs_raw.insert(curr_dir.join("/").to_string(), 0);
+        } else if line.starts_with("$ ls") {
+            continue;
+        } else if line.starts_with('$') {
+            return Err(source_line.error(line, "unknown command"));
+        } else if line.starts_with("dir") {
+            let dir: String = curr_dir.join("/") + "/" + line.trim_start_matches("dir ");
+            dirs_raw.entry(dir).or_insert(0);
+        } else {
+            let (size, _) = source_line.split_once(line, " ")?;
+            let file_size: u32 = source_line.parse::<u32>(size)?;
+            *dirs_raw.entry(curr_dir.join("/")).or_insert(0) += file_size;
+        }
+    }
+
+    let mut dirs_evaled: HashMap<String, u32> = dirs_raw.clone();
+
+    for (k_1, v_1) in &dirs_raw {
+        for k_2 in dirs_raw.keys() {
+            if k_1 == k_2 {
+                continue;
+            };
+            // A plain substring match would also count siblings like "a" and "ab"
+            if k_1.starts_with(&format!("{}/", k_2)) {
+                dirs_evaled.insert(k_2.to_string(), dirs_evaled.get(k_2).unwrap() + v_1);
+            };
+        }
+    }
+
+    return Ok(dirs_evaled);
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<u32, ParseError> {
+    let dirs_evaled = evaluate_dirs(input)?;
+    let sum: u32 = dirs_evaled
+        .iter()
+        .filter(|item| item.1 <= &params.max_dir_size)
+        .map(|item| item.1)
+        .sum::<u32>();
+
+    Ok(sum)
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<u32, ParseError> {
+    let dirs_evaled = evaluate_dirs(input)?;
+    let mut dirs_evaled_sorted: Vec<(&String, &u32)> = dirs_evaled.iter().collect();
+    dirs_evaled_sorted.sort_by(|a, b| a.1.cmp(b.1));
+
+    let root_dir_size: i32 = *dirs_evaled_sorted
+        .iter()
+        .find(|item| item.0 == "/")
+        .ok_or_else(|| ParseError::whole_input("the input never changes into '/'"))?
+        .1 as i32;
+    let available_storage: i32 = params.disk_size - root_dir_size;
+
+    Ok(*dirs_evaled_sorted
+        .iter()
+        .find(|item| available_storage + *item.1 as i32 >= params.required_space)
+        .ok_or_else(|| {
+            ParseError::whole_input(format!(
+                "even deleting '/' does not leave {} free on a disk of {}",
+                params.required_space, params.disk_size
+            ))
+        })?
+        .1)
+}
+
+struct GeneratedDir {
+    name: String,
+    children: Vec<usize>,
+    files: Vec<(String, u32)>,
+}
+
+// Short names from a small alphabet so that sibling names often share prefixes
+fn unique_name(rng: &mut Rng, taken: &[String], suffix: &str) -> String {
+    let mut name = String::new();
+    for _ in 0..rng.range(1..=3) {
+        name.push(*rng.choose(&['a', 'b', 'c', 'd', 'e']));
+    }
+    while taken.contains(&(name.clone() + suffix)) {
+        name.push(*rng.choose(&['a', 'b', 'c', 'd', 'e']));
+    }
+    return name + suffix;
+}
+
+fn generate_listing(rng: &mut Rng, dirs: &[GeneratedDir], dir: usize, out: &mut String) {
+    let mut entries = dirs[dir]
+        .children
+        .iter()
+        .map(|&child| format!("dir {}", dirs[child].name))
+        .chain(
+            dirs[dir]
+                .files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut entries);
+
+    out.push_str("$ ls\n");
+    for entry in entries {
+        out.push_str(&entry);
+        out.push('\n');
+    }
+    for &child in &dirs[dir].children {
+        out.push_str(&format!("$ cd {}\n", dirs[child].name));
+        generate_listing(rng, dirs, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut dirs = vec![GeneratedDir {
+        name: String::from("/"),
+        children: vec![],
+        files: vec![],
+    }];
+    for i in 1..size.max(1) {
+        let parent = rng.index(i);
+        let taken = dirs[parent]
+            .children
+            .iter()
+            .map(|&c| dirs[c].name.clone())
+            .collect::<Vec<String>>();
+        let name = unique_name(rng, &taken, "");
+        dirs[parent].children.push(i);
+        dirs.push(GeneratedDir {
+            name,
+            children: vec![],
+            files: vec![],
+        });
+    }
+    for i in 0..dirs.len() {
+        let mut taken = dirs[i]
+            .children
+            .iter()
+            .map(|&c| dirs[c].name.clone())
+            .collect::<Vec<String>>();
+        for _ in 0..rng.range(0..=4) {
+            let suffix = *rng.choose(&["", ".txt", ".dat"]);
+            let name = unique_name(rng, &taken, suffix);
+            taken.push(name.clone());
+            dirs[i].files.push((name, rng.range(1..=300000) as u32));
+        }
+    }
+    // Fill the disk far enough that part 2 always has to delete something
+    let params = Params::default();
+    let mut used = dirs.iter().flat_map(|d| &d.files).map(|f| f.1).sum::<u32>();
+    while used as i32 <= params.disk_size - params.required_space {
+        let i = rng.index(dirs.len());
+        let mut taken = dirs[i]
+            .files
+            .iter()
+            .map(|f| f.0.clone())
+            .collect::<Vec<String>>();
+        taken.extend(dirs[i].children.iter().map(|&c| dirs[c].name.clone()));
+        let size = rng.range(100000..=3000000) as u32;
+        dirs[i].files.push((unique_name(rng, &taken, ".bin"), size));
+        used += size;
+    }
+
+    let mut out = String::from("$ cd /\n");
+    generate_listing(rng, &dirs, 0, &mut out);
+    return out;
+}
@@ -0,0 +1,32 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_7::part1(EXAMPLE), Ok(95437));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_7::part2(EXAMPLE), Ok(24933642));
+}
+
+#[test]
+fn sibling_dirs_sharing_a_prefix() {
+    let input = "$ cd /\n$ ls\ndir a\ndir ab\n$ cd a\n$ ls\n100 x\n$ cd ..\n$ cd ab\n$ ls\n200 y\n";
+    // "/" holds 300, "a" 100 and "ab" 200; "a" must not count the files of "ab"
+    assert_eq!(day_7::part1(input), Ok(600));
+}
+
+#[test]
+fn unsolvable_part2_is_an_error() {
+    let error = day_7::part2("").unwrap_err();
+    assert_eq!(error.message, "the input never changes into '/'");
+
+    let params = day_7::Params {
+        required_space: 80000000,
+        ..day_7::Params::default()
+    };
+    let error = day_7::part2_with(EXAMPLE, &params).unwrap_err();
+    assert_eq!(error.line, 0);
+    assert_eq!(error.render(EXAMPLE), error.message);
+}
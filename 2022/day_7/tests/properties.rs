@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use aoc_common::Rng;
+
+// Directory sizes keyed by their full path as a list of names
+fn naive_sizes(input: &str) -> HashMap<Vec<String>, u32> {
+    let mut cwd: Vec<String> = vec![];
+    let mut sizes = HashMap::from([(vec![], 0)]);
+    for line in input.lines() {
+        let words = line.split(' ').collect::<Vec<&str>>();
+        match words[..] {
+            ["$", "cd", "/"] => cwd.clear(),
+            ["$", "cd", ".."] => {
+                cwd.pop();
+            }
+            ["$", "cd", dir] => cwd.push(dir.to_string()),
+            ["$", "ls"] => (),
+            ["dir", dir] => {
+                let mut path = cwd.clone();
+                path.push(dir.to_string());
+                sizes.entry(path).or_insert(0);
+            }
+            [size, _] => {
+                let size = size.parse::<u32>().unwrap();
+                for depth in 0..=cwd.len() {
+                    *sizes.entry(cwd[..depth].to_vec()).or_insert(0) += size;
+                }
+            }
+            _ => panic!("unexpected line {}", line),
+        }
+    }
+    sizes
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    let params = day_7::Params::default();
+    for seed in 0..50 {
+        let input = day_7::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let sizes = naive_sizes(&input);
+        let part1 = sizes.values().filter(|&&s| s <= params.max_dir_size).sum();
+        let free = params.disk_size - sizes[&vec![]] as i32;
+        let part2 = sizes
+            .values()
+            .copied()
+            .filter(|&s| free + s as i32 >= params.required_space)
+            .min()
+            .unwrap();
+        assert_eq!(day_7::part1(&input), Ok(part1), "seed {}", seed);
+        assert_eq!(day_7::part2(&input), Ok(part2), "seed {}", seed);
+    }
+}
+
+#[test]
+fn sibling_names_sharing_a_prefix() {
+    let input = "$ cd /\n$ ls\ndir a\ndir ab\n$ cd a\n$ ls\n10 x\n$ cd ..\n$ cd ab\n$ ls\n20 y\n";
+    assert_eq!(day_7::part1(input), Ok(10 + 20 + 30));
+}
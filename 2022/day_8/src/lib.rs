@@ -0,0 +1,59 @@
+use aoc_common::{Dir4, Grid, ParseError, Rng};
+
+pub fn parse_trees(input: &str) -> Result<Grid<u32>, ParseError> {
+    return Grid::parse(input, |c| c.to_digit(10).ok_or("expected a digit"));
+}
+
+pub fn survey_trees(input: &str) -> Result<(usize, usize), ParseError> {
+    let mut visibility_counter = 0;
+    let mut tree_house_score = 0;
+    let grid = parse_trees(input)?;
+
+    for (pos, &height) in grid.iter() {
+        let mut visible = false;
+        let mut curr_tree_house_score = 1;
+
+        for dir in Dir4::ALL {
+            let mut viewing_distance = 0;
+            let mut blocked = false;
+            for (_, &tree) in grid.ray(pos, dir.delta()) {
+                viewing_distance += 1;
+                if tree >= height {
+                    blocked = true;
+                    break;
+                }
+            }
+            visible |= !blocked;
+            curr_tree_house_score *= viewing_distance;
+        }
+
+        if visible {
+            visibility_counter += 1;
+        }
+        if tree_house_score < curr_tree_house_score {
+            tree_house_score = curr_tree_house_score;
+        }
+    }
+
+    return Ok((visibility_counter, tree_house_score));
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(survey_trees(input)?.0)
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(survey_trees(input)?.1)
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let grid = Grid::from_cells(
+        side,
+        side,
+        (0..side * side)
+            .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+            .collect(),
+    );
+    return grid.to_string() + "\n";
+}
@@ -0,0 +1,11 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_8::part1(EXAMPLE), Ok(21));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_8::part2(EXAMPLE), Ok(8));
+}
@@ -0,0 +1,44 @@
+use aoc_common::Rng;
+
+fn naive_survey(input: &str) -> (usize, usize) {
+    let grid = input
+        .lines()
+        .map(|l| l.bytes().collect::<Vec<u8>>())
+        .collect::<Vec<Vec<u8>>>();
+    let (height, width) = (grid.len(), grid[0].len());
+    let mut visible = 0;
+    let mut best = 0;
+    for y in 0..height {
+        for x in 0..width {
+            let tree = grid[y][x];
+            let lines_of_sight: [Vec<u8>; 4] = [
+                (0..y).rev().map(|yy| grid[yy][x]).collect(),
+                (y + 1..height).map(|yy| grid[yy][x]).collect(),
+                (0..x).rev().map(|xx| grid[y][xx]).collect(),
+                (x + 1..width).map(|xx| grid[y][xx]).collect(),
+            ];
+            if lines_of_sight.iter().any(|l| l.iter().all(|&t| t < tree)) {
+                visible += 1;
+            }
+            let score = lines_of_sight
+                .iter()
+                .map(|l| match l.iter().position(|&t| t >= tree) {
+                    Some(i) => i + 1,
+                    None => l.len(),
+                })
+                .product::<usize>();
+            best = best.max(score);
+        }
+    }
+    (visible, best)
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_8::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let (part1, part2) = naive_survey(&input);
+        assert_eq!(day_8::part1(&input), Ok(part1), "seed {}", seed);
+        assert_eq!(day_8::part2(&input), Ok(part2), "seed {}", seed);
+    }
+}
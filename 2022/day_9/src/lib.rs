@@ -0,0 +1,172 @@
+use std::{collections::HashSet, io::BufRead};
+
+use aoc_common::{
+    for_each_line, source_lines, Dir4, Grid, ParseError, Point2, Rng, SourceLine, Visualize,
+};
+
+pub fn parse_direction(line: &SourceLine, s: &str) -> Result<Dir4, ParseError> {
+    Dir4::parse(s)
+        .filter(|_| matches!(s, "L" | "R" | "U" | "D"))
+        .ok_or_else(|| line.error(s, "invalid direction, expected L, R, U or D"))
+}
+
+pub fn parse_move(line: &SourceLine) -> Result<(Dir4, i32), ParseError> {
+    let mut instruction = line.text.split_whitespace();
+    let curr_step_dir = parse_direction(
+        line,
+        instruction
+            .next()
+            .ok_or_else(|| line.error(line.end(), "missing direction"))?,
+    )?;
+    let steps = line.parse::<i32>(
+        instruction
+            .next()
+            .ok_or_else(|| line.error(line.end(), "no steps provided"))?,
+    )?;
+    return Ok((curr_step_dir, steps));
+}
+
+pub fn parse_moves(input: &str) -> Result<Vec<(Dir4, i32)>, ParseError> {
+    let mut moves = vec![];
+
+    for line in source_lines(input) {
+        moves.push(parse_move(&line)?);
+    }
+    return Ok(moves);
+}
+
+pub fn correct_tail_movement(head: Point2<isize>, tail: &mut Point2<isize>) {
+    let diff = head - *tail;
+    if diff.chebyshev() > 1 {
+        *tail += diff.signum();
+    }
+}
+
+pub fn simulate_rope(input: &str) -> Result<Vec<HashSet<Point2<isize>>>, ParseError> {
+    let moves = parse_moves(input)?;
+    let mut step_collector = vec![];
+
+    for knots in [2, 10] {
+        let mut rope = Rope::new(moves.clone(), knots);
+        rope.simulate(&mut |_| ());
+        step_collector.push(rope.visited);
+    }
+    return Ok(step_collector);
+}
+
+pub struct Rope {
+    moves: Vec<(Dir4, i32)>,
+    pub knots: Vec<Point2<isize>>,
+    pub visited: HashSet<Point2<isize>>,
+}
+
+impl Rope {
+    pub fn new(moves: Vec<(Dir4, i32)>, knots: usize) -> Self {
+        Self {
+            moves,
+            knots: vec![Point2::new(0, 0); knots],
+            visited: HashSet::from([Point2::new(0, 0)]),
+        }
+    }
+
+    pub fn from_input(input: &str, knots: usize) -> Result<Self, ParseError> {
+        Ok(Self::new(parse_moves(input)?, knots))
+    }
+
+    pub fn step(&mut self, dir: Dir4) {
+        self.knots[0] += dir.delta();
+        for i in 0..self.knots.len() - 1 {
+            correct_tail_movement(self.knots[i], &mut self.knots[i + 1]);
+        }
+        self.visited.insert(self.knots[self.knots.len() - 1]);
+    }
+
+    fn simulate(&mut self, on_step: &mut dyn FnMut(&Rope)) {
+        for m in 0..self.moves.len() {
+            let (dir, steps) = self.moves[m];
+            for _ in 0..steps {
+                self.step(dir);
+                on_step(self);
+            }
+        }
+    }
+
+    // The other knots never leave the area the head has been in
+    fn head_bounds(&self) -> (Point2<isize>, Point2<isize>) {
+        let mut head = self.knots[0];
+        let (mut low, mut high) = (head, head);
+        for &(dir, steps) in &self.moves {
+            head += dir.delta() * steps as isize;
+            low = Point2::new(low.x.min(head.x), low.y.min(head.y));
+            high = Point2::new(high.x.max(head.x), high.y.max(head.y));
+        }
+        return (low, high);
+    }
+}
+
+impl Visualize for Rope {
+    fn visualize(&mut self, frame: &mut dyn FnMut(&Grid<char>)) {
+        let (low, high) = self.head_bounds();
+        let empty = Grid::new(
+            (high.x - low.x + 1) as usize,
+            (high.y - low.y + 1) as usize,
+            '.',
+        );
+        let to_cell =
+            |p: Point2<isize>| Point2::new((p.x - low.x) as usize, (p.y - low.y) as usize);
+
+        let mut draw = |rope: &Rope| {
+            let mut grid = empty.clone();
+            for &p in &rope.visited {
+                grid[to_cell(p)] = '#';
+            }
+            grid[to_cell(Point2::new(0, 0))] = 's';
+            for (i, &knot) in rope.knots.iter().enumerate().rev() {
+                grid[to_cell(knot)] = match i {
+                    0 => 'H',
+                    _ => char::from_digit(i as u32 % 10, 10).unwrap(),
+                };
+            }
+            frame(&grid);
+        };
+        draw(self);
+        self.simulate(&mut draw);
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(simulate_rope(input)?[0].len())
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(simulate_rope(input)?[1].len())
+}
+
+// Applies each move as it is read; only the visited positions are kept
+pub fn solve_stream<R: BufRead>(reader: R) -> Result<(usize, usize), ParseError> {
+    let mut ropes = [Rope::new(vec![], 2), Rope::new(vec![], 10)];
+
+    for_each_line(reader, |line| {
+        let (dir, steps) = parse_move(&line)?;
+        for _ in 0..steps {
+            for rope in ropes.iter_mut() {
+                rope.step(dir);
+            }
+        }
+        return Ok(());
+    })?;
+
+    return Ok((ropes[0].visited.len(), ropes[1].visited.len()));
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&["L", "R", "U", "D"]),
+                rng.range(1..=20)
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+const EXAMPLE: &str = include_str!("../test_input_p1");
+const SMALL_EXAMPLE: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n";
+
+#[test]
+fn part1_example() {
+    assert_eq!(day_9::part1(SMALL_EXAMPLE), Ok(13));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(day_9::part2(SMALL_EXAMPLE), Ok(1));
+    assert_eq!(day_9::part2(EXAMPLE), Ok(36));
+}
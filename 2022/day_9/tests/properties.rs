@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+fn naive_visited(input: &str, knots: usize) -> usize {
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for line in input.lines() {
+        let (dir, steps) = line.split_once(' ').unwrap();
+        let (dx, dy) = match dir {
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            "U" => (0, -1),
+            _ => (0, 1),
+        };
+        for _ in 0..steps.parse::<u32>().unwrap() {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for i in 1..knots {
+                let (hx, hy) = rope[i - 1];
+                let (tx, ty) = &mut rope[i];
+                if (hx - *tx).abs() > 1 || (hy - *ty).abs() > 1 {
+                    *tx += (hx - *tx).signum();
+                    *ty += (hy - *ty).signum();
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+#[test]
+fn matches_naive_on_generated_inputs() {
+    for seed in 0..50 {
+        let input = day_9::generate(&mut Rng::new(seed), 1 + seed as usize * 10);
+        assert_eq!(
+            day_9::part1(&input),
+            Ok(naive_visited(&input, 2)),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            day_9::part2(&input),
+            Ok(naive_visited(&input, 10)),
+            "seed {}",
+            seed
+        );
+    }
+}
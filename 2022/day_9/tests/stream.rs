@@ -0,0 +1,24 @@
+use aoc_common::Rng;
+
+const EXAMPLE: &str = include_str!("../test_input_p1");
+
+#[test]
+fn matches_in_memory_solution() {
+    let generated =
+        (0..20).map(|seed| day_9::generate(&mut Rng::new(seed), 1 + seed as usize * 10));
+    for input in std::iter::once(EXAMPLE.to_string()).chain(generated) {
+        assert_eq!(
+            day_9::solve_stream(input.as_bytes()),
+            Ok((day_9::part1(&input).unwrap(), day_9::part2(&input).unwrap()))
+        );
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    let input = "R 4\nX 2\n";
+    assert_eq!(
+        day_9::solve_stream(input.as_bytes()).unwrap_err(),
+        day_9::part1(input).unwrap_err()
+    );
+}
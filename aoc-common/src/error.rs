@@ -0,0 +1,155 @@
+use std::{any, error, fmt, io::BufRead, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    // For a problem with the input as a whole, such as a puzzle without a
+    // solution, rather than with one place in it
+    pub fn whole_input(message: impl Into<String>) -> Self {
+        Self::new(0, 0, "", message)
+    }
+
+    pub fn render(&self, input: &str) -> String {
+        if self.line == 0 {
+            return self.message.clone();
+        }
+        let source = input
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or_default();
+        let gutter = self.line.to_string().len();
+        format!(
+            "{} at line {}, column {}\n{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+            self.message,
+            self.line,
+            self.column,
+            "",
+            self.line,
+            source,
+            "",
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.text.chars().count().max(1)),
+            gutter = gutter
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.message);
+        }
+        write!(
+            f,
+            "{} at line {}, column {}: '{}'",
+            self.message, self.line, self.column, self.text
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[derive(Clone, Copy)]
+pub struct SourceLine<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> SourceLine<'a> {
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Self { index, text }
+    }
+
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let pos = token.as_ptr() as usize;
+        let offset = if pos >= start && pos <= start + self.text.len() {
+            pos - start
+        } else {
+            self.text.find(token).unwrap_or(0)
+        };
+        self.text[..offset].chars().count() + 1
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.index + 1, self.column_of(token), token, message)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error(token, format!("expected {}", any::type_name::<T>())))
+    }
+
+    pub fn strip_prefix(&self, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        token
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(token, format!("expected '{}'", prefix.trim())))
+    }
+
+    pub fn split_once(
+        &self,
+        token: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        token
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(token, format!("expected '{}'", delimiter.trim())))
+    }
+
+    // Separates groups of lines, see `groups`
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+}
+
+pub fn source_lines(input: &str) -> impl Iterator<Item = SourceLine<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| SourceLine::new(index, text))
+}
+
+// Like `source_lines`, but reads one line at a time into a reused buffer so
+// that memory use does not grow with the size of the input
+pub fn for_each_line<R: BufRead>(
+    mut reader: R,
+    mut f: impl FnMut(SourceLine) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    let mut buffer = String::new();
+    let mut index = 0;
+    loop {
+        buffer.clear();
+        let read = reader.read_line(&mut buffer).map_err(|e| {
+            ParseError::new(index + 1, 1, "", format!("unable to read input: {}", e))
+        })?;
+        if read == 0 {
+            return Ok(());
+        }
+        let text = match buffer.strip_suffix('\n') {
+            Some(text) => text.strip_suffix('\r').unwrap_or(text),
+            None => &buffer,
+        };
+        f(SourceLine::new(index, text))?;
+        index += 1;
+    }
+}
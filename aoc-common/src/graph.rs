@@ -0,0 +1,178 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+
+    fn weight(&self, _from: &Self::Node, _to: &Self::Node) -> usize {
+        1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: usize,
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(parent) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(Path {
+                nodes: reconstruct(&parents, node),
+                cost: distance,
+            });
+        }
+        for next in graph.neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    None
+}
+
+pub fn bfs_distances<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> HashMap<G::Node, usize> {
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in graph.neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, starts, is_goal, |_| 0)
+}
+
+// The heuristic must never overestimate the remaining cost
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Option<Path<G::Node>> {
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut nodes = vec![];
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if costs.insert(start.clone(), 0).is_none() {
+            queue.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        let node = nodes[i].clone();
+        if costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(Path {
+                nodes: reconstruct(&parents, node),
+                cost,
+            });
+        }
+        for next in graph.neighbours(&node) {
+            let next_cost = cost + graph.weight(&node, &next);
+            if costs.get(&next).is_none_or(|&c| next_cost < c) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                queue.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+    None
+}
+
+// distances[i][j] is the shortest distance from nodes[i] to nodes[j], None if unreachable
+pub fn floyd_warshall<G: Graph>(graph: &G, nodes: &[G::Node]) -> Vec<Vec<Option<usize>>> {
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node, i))
+        .collect::<HashMap<&G::Node, usize>>();
+    let mut distances = vec![vec![None; nodes.len()]; nodes.len()];
+
+    for (i, node) in nodes.iter().enumerate() {
+        distances[i][i] = Some(0);
+        for next in graph.neighbours(node) {
+            if let Some(&j) = index.get(&next) {
+                let weight = graph.weight(node, &next);
+                if distances[i][j].is_none_or(|d| weight < d) {
+                    distances[i][j] = Some(weight);
+                }
+            }
+        }
+    }
+
+    for k in 0..nodes.len() {
+        let via = distances[k].clone();
+        for row in distances.iter_mut() {
+            let Some(ik) = row[k] else {
+                continue;
+            };
+            for (ij, kj) in row.iter_mut().zip(&via) {
+                if let Some(kj) = kj {
+                    if ij.is_none_or(|d| ik + kj < d) {
+                        *ij = Some(ik + kj);
+                    }
+                }
+            }
+        }
+    }
+    distances
+}
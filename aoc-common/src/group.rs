@@ -0,0 +1,94 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::{for_each_line, source_lines, ParseError, SourceLine};
+
+// A run of non-blank lines. Blank lines, including whitespace-only ones and
+// repeated ones, only ever separate groups.
+pub struct Group<'a> {
+    // 1-based position among the groups
+    pub number: usize,
+    pub lines: Vec<SourceLine<'a>>,
+}
+
+impl<'a> Group<'a> {
+    pub fn first(&self) -> SourceLine<'a> {
+        self.lines[0]
+    }
+
+    pub fn last(&self) -> SourceLine<'a> {
+        self.lines[self.lines.len() - 1]
+    }
+
+    // Names the group in an error raised while parsing one of its lines
+    pub fn context(&self, error: ParseError) -> ParseError {
+        ParseError {
+            message: format!("{} in group {}", error.message, self.number),
+            ..error
+        }
+    }
+
+    pub fn error(&self, line: SourceLine, token: &str, message: impl Into<String>) -> ParseError {
+        self.context(line.error(token, message))
+    }
+
+    // One value per line, ignoring surrounding whitespace
+    pub fn parse_lines<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.lines
+            .iter()
+            .map(|line| line.parse::<T>(line.text.trim()))
+            .collect::<Result<Vec<T>, ParseError>>()
+            .map_err(|e| self.context(e))
+    }
+}
+
+pub fn groups(input: &str) -> impl Iterator<Item = Group<'_>> {
+    let mut lines = source_lines(input).peekable();
+    let mut number = 0;
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.is_blank()).is_some() {}
+        let mut group = vec![];
+        while let Some(line) = lines.next_if(|line| !line.is_blank()) {
+            group.push(line);
+        }
+        if group.is_empty() {
+            return None;
+        }
+        number += 1;
+        Some(Group {
+            number,
+            lines: group,
+        })
+    })
+}
+
+// Like `groups`, but reads one line at a time and only keeps the current group
+pub fn for_each_group<R: BufRead>(
+    reader: R,
+    mut f: impl FnMut(Group) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    let mut pending: Vec<(usize, String)> = vec![];
+    let mut number = 0;
+    let mut flush = |pending: &mut Vec<(usize, String)>| {
+        if pending.is_empty() {
+            return Ok(());
+        }
+        number += 1;
+        let lines = pending
+            .iter()
+            .map(|(index, text)| SourceLine::new(*index, text))
+            .collect();
+        let result = f(Group { number, lines });
+        pending.clear();
+        result
+    };
+
+    for_each_line(reader, |line| {
+        if line.is_blank() {
+            flush(&mut pending)
+        } else {
+            pending.push((line.index, line.text.to_string()));
+            Ok(())
+        }
+    })?;
+    flush(&mut pending)
+}
@@ -0,0 +1,161 @@
+use std::ops::RangeInclusive;
+
+use crate::Coord;
+
+// Closed integer intervals, kept sorted, disjoint and non-adjacent
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+fn touches<T: Coord>(end: T, start: T) -> bool {
+    start <= end || start - end == T::ONE
+}
+
+impl<T: Coord + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.intervals.partition_point(|&(_, e)| !touches(e, start));
+        let last = self.intervals.partition_point(|&(s, _)| touches(end, s));
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(s, e)| s..=e)
+    }
+
+    pub fn covered_len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |acc, &(s, e)| acc + (e - s) + T::ONE)
+    }
+
+    fn find(&self, value: T) -> Option<(T, T)> {
+        let i = self.intervals.partition_point(|&(s, _)| s <= value);
+        if i > 0 && self.intervals[i - 1].1 >= value {
+            return Some(self.intervals[i - 1]);
+        }
+        None
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.find(value).is_some()
+    }
+
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        match self.find(*range.start()) {
+            Some((_, e)) => e >= *range.end(),
+            None => range.is_empty(),
+        }
+    }
+
+    pub fn overlaps(&self, range: RangeInclusive<T>) -> bool {
+        let i = self.intervals.partition_point(|&(_, e)| e < *range.start());
+        i < self.intervals.len() && self.intervals[i].0 <= *range.end() && !range.is_empty()
+    }
+
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|w| w[0].1 + T::ONE..=w[1].0 - T::ONE)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (s1, e1) = self.intervals[i];
+            let (s2, e2) = other.intervals[j];
+            if s1.max(s2) <= e1.min(e2) {
+                intervals.push((s1.max(s2), e1.min(e2)));
+            }
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut j = 0;
+        for &(s, e) in &self.intervals {
+            while j < other.intervals.len() && other.intervals[j].1 < s {
+                j += 1;
+            }
+            let mut start = s;
+            let mut remainder = true;
+            for &(os, oe) in other.intervals[j..].iter().take_while(|&&(os, _)| os <= e) {
+                if os > start {
+                    intervals.push((start, os - T::ONE));
+                }
+                if oe >= e {
+                    remainder = false;
+                    break;
+                }
+                start = start.max(oe + T::ONE);
+            }
+            if remainder {
+                intervals.push((start, e));
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Coord + Ord> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coord + Ord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(s, e)| s <= e)
+            .collect::<Vec<(T, T)>>();
+        ranges.sort_unstable();
+
+        let mut intervals: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (s, e) in ranges {
+            match intervals.last_mut() {
+                Some(last) if touches(last.1, s) => last.1 = last.1.max(e),
+                _ => intervals.push((s, e)),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Coord + Ord> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
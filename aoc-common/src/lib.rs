@@ -0,0 +1,19 @@
+mod answer;
+mod error;
+mod graph;
+mod grid;
+mod group;
+mod interval;
+mod point;
+mod rng;
+mod visualize;
+
+pub use answer::Answer;
+pub use error::{for_each_line, source_lines, ParseError, SourceLine};
+pub use graph::{astar, bfs, bfs_distances, dijkstra, floyd_warshall, Graph, Path};
+pub use grid::{Grid, Pos};
+pub use group::{for_each_group, groups, Group};
+pub use interval::IntervalSet;
+pub use point::{Coord, Dir4, Dir8, Point2, Vec2};
+pub use rng::Rng;
+pub use visualize::Visualize;
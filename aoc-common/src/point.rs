@@ -0,0 +1,302 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{ParseError, SourceLine};
+
+pub trait Coord:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! coord_signed {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+macro_rules! coord_unsigned {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn signum(self) -> Self {
+                (self > 0) as $t
+            }
+        }
+    )*};
+}
+
+coord_signed!(i8, i16, i32, i64, isize);
+coord_unsigned!(u8, u16, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let dx = self.x.distance(other.x);
+        let dy = self.y.distance(other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl<T: FromStr> Point2<T> {
+    // Accepts both "3,4" and "x=3, y=4"
+    pub fn parse<'a>(line: &SourceLine<'a>, token: &'a str) -> Result<Self, ParseError> {
+        let (x, y) = line.split_once(token, ",")?;
+        let (x, y) = (x.trim(), y.trim());
+        if x.starts_with("x=") {
+            return Ok(Self::new(
+                line.parse(line.strip_prefix(x, "x=")?)?,
+                line.parse(line.strip_prefix(y, "y=")?)?,
+            ));
+        }
+        Ok(Self::new(line.parse(x)?, line.parse(y)?))
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Vec2<T> {
+    pub fn manhattan(&self) -> T {
+        self.x.distance(T::ZERO) + self.y.distance(T::ZERO)
+    }
+
+    pub fn chebyshev(&self) -> T {
+        Point2::new(T::ZERO, T::ZERO).chebyshev(&Point2::new(self.x, self.y))
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Coord> Add<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, v: Vec2<T>) -> Self {
+        Self::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl<T: Coord> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, v: Vec2<T>) {
+        *self = *self + v;
+    }
+}
+
+impl<T: Coord> Sub<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, v: Vec2<T>) -> Self {
+        Self::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl<T: Coord> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, v: Vec2<T>) {
+        *self = *self - v;
+    }
+}
+
+impl<T: Coord> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Self) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+// Screen orientation: y grows downwards, so Up is y - 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "U" | "N" | "^" => Some(Dir4::Up),
+            "R" | "E" | ">" => Some(Dir4::Right),
+            "D" | "S" | "v" => Some(Dir4::Down),
+            "L" | "W" | "<" => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub const fn delta(self) -> Vec2<isize> {
+        match self {
+            Dir4::Up => Vec2::new(0, -1),
+            Dir4::Right => Vec2::new(1, 0),
+            Dir4::Down => Vec2::new(0, 1),
+            Dir4::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub const fn delta(self) -> Vec2<isize> {
+        match self {
+            Dir8::Up => Vec2::new(0, -1),
+            Dir8::UpRight => Vec2::new(1, -1),
+            Dir8::Right => Vec2::new(1, 0),
+            Dir8::DownRight => Vec2::new(1, 1),
+            Dir8::Down => Vec2::new(0, 1),
+            Dir8::DownLeft => Vec2::new(-1, 1),
+            Dir8::Left => Vec2::new(-1, 0),
+            Dir8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
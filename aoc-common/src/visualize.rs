@@ -0,0 +1,12 @@
+use crate::Grid;
+
+pub trait Visualize {
+    // Runs the simulation, handing every intermediate state to `frame`
+    fn visualize(&mut self, frame: &mut dyn FnMut(&Grid<char>));
+
+    fn frames(&mut self) -> Vec<Grid<char>> {
+        let mut frames = vec![];
+        self.visualize(&mut |f| frames.push(f.clone()));
+        frames
+    }
+}
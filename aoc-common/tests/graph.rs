@@ -0,0 +1,106 @@
+use aoc_common::{
+    astar, bfs, bfs_distances, dijkstra, floyd_warshall, Graph, Grid, Path, Point2, Pos,
+};
+
+// Directed, weighted: 0 -1-> 1 -1-> 3, 0 -5-> 3, 1 -1-> 2, 4 is isolated
+struct Weighted;
+
+impl Graph for Weighted {
+    type Node = u8;
+
+    fn neighbours(&self, node: &u8) -> impl Iterator<Item = u8> {
+        let next: &[u8] = match node {
+            0 => &[3, 1],
+            1 => &[2, 3],
+            _ => &[],
+        };
+        next.iter().copied()
+    }
+
+    fn weight(&self, from: &u8, to: &u8) -> usize {
+        match (from, to) {
+            (0, 3) => 5,
+            _ => 1,
+        }
+    }
+}
+
+struct Maze(Grid<char>);
+
+impl Graph for Maze {
+    type Node = Pos;
+
+    fn neighbours(&self, pos: &Pos) -> impl Iterator<Item = Pos> {
+        self.0.neighbours4(*pos).filter(|&p| self.0[p] != '#')
+    }
+}
+
+const MAZE: &str = "\
+..#....
+.##.##.
+.....#.
+.###...
+";
+
+fn maze() -> Maze {
+    Maze(Grid::parse(MAZE, Ok::<char, String>).unwrap())
+}
+
+#[test]
+fn bfs_counts_edges_and_reconstructs_the_path() {
+    let path = bfs(&Weighted, [0], |&n| n == 3).unwrap();
+    assert_eq!(
+        path,
+        Path {
+            nodes: vec![0, 3],
+            cost: 1
+        }
+    );
+    assert_eq!(bfs(&Weighted, [0], |&n| n == 4), None);
+    assert_eq!(bfs(&Weighted, [2, 4], |&n| n == 4).unwrap().cost, 0);
+
+    let distances = bfs_distances(&Weighted, [0]);
+    assert_eq!(distances.len(), 4);
+    assert_eq!(distances[&2], 2);
+}
+
+#[test]
+fn dijkstra_uses_weights() {
+    let path = dijkstra(&Weighted, [0], |&n| n == 3).unwrap();
+    assert_eq!(
+        path,
+        Path {
+            nodes: vec![0, 1, 3],
+            cost: 2
+        }
+    );
+}
+
+#[test]
+fn astar_agrees_with_bfs_on_a_maze() {
+    let maze = maze();
+    let goal = Point2::new(6, 0);
+    let by_bfs = bfs(&maze, [Point2::new(0, 0)], |&p| p == goal).unwrap();
+    let by_astar = astar(
+        &maze,
+        [Point2::new(0, 0)],
+        |&p| p == goal,
+        |p| p.manhattan(&goal),
+    )
+    .unwrap();
+    assert_eq!(by_bfs.cost, 10);
+    assert_eq!(by_astar.cost, by_bfs.cost);
+    assert_eq!(by_astar.nodes.len(), 11);
+    assert!(by_astar
+        .nodes
+        .windows(2)
+        .all(|w| w[0].manhattan(&w[1]) == 1 && maze.0[w[1]] == '.'));
+}
+
+#[test]
+fn floyd_warshall_all_pairs() {
+    let distances = floyd_warshall(&Weighted, &[0, 1, 2, 3, 4]);
+    assert_eq!(distances[0], vec![Some(0), Some(1), Some(2), Some(2), None]);
+    assert_eq!(distances[3][0], None);
+    assert_eq!(distances[4][4], Some(0));
+}
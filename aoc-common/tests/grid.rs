@@ -0,0 +1,111 @@
+use aoc_common::{Grid, ParseError, Point2, Vec2};
+
+const MAP: &str = "abc\ndef\nghi\n";
+
+fn letters() -> Grid<char> {
+    Grid::parse(MAP, Ok::<char, String>).unwrap()
+}
+
+#[test]
+fn parses_char_map() {
+    let grid = letters();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid[Point2::new(2, 0)], 'c');
+    assert_eq!(grid[Point2::new(0, 2)], 'g');
+    assert_eq!(grid.to_string(), MAP.trim_end());
+}
+
+#[test]
+fn reports_invalid_cells_and_ragged_rows() {
+    let digits = Grid::parse("12\n3x\n", |c| c.to_digit(10).ok_or("expected a digit"));
+    assert_eq!(digits, Err(ParseError::new(2, 2, "x", "expected a digit")));
+
+    let ragged = Grid::parse("12\n345\n", |c| c.to_digit(10).ok_or("expected a digit"));
+    assert_eq!(
+        ragged,
+        Err(ParseError::new(
+            2,
+            1,
+            "345",
+            "row width differs from first row"
+        ))
+    );
+
+    assert!(Grid::parse("", Ok::<char, String>).is_err());
+}
+
+#[test]
+fn bounds_checked_access() {
+    let mut grid = letters();
+    assert_eq!(grid.get(Point2::new(3, 0)), None);
+    assert_eq!(grid.get(Point2::new(0, 3)), None);
+    assert_eq!(grid.offset(Point2::new(0, 0), Vec2::new(-1, 0)), None);
+    assert_eq!(
+        grid.offset(Point2::new(0, 0), Vec2::new(1, 1)),
+        Some(Point2::new(1, 1))
+    );
+
+    *grid.get_mut(Point2::new(1, 1)).unwrap() = '#';
+    assert_eq!(grid.row(1), &['d', '#', 'f']);
+    assert!(grid.get_mut(Point2::new(1, 3)).is_none());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn index_out_of_bounds_panics() {
+    let _ = letters()[Point2::new(3, 3)];
+}
+
+#[test]
+fn neighbours_stay_in_bounds() {
+    let grid = letters();
+    let mut corner = grid.neighbours4(Point2::new(0, 0)).collect::<Vec<_>>();
+    corner.sort();
+    assert_eq!(corner, vec![Point2::new(0, 1), Point2::new(1, 0)]);
+    assert_eq!(grid.neighbours4(Point2::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8(Point2::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Point2::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn rows_columns_and_rays() {
+    let grid = letters();
+    assert_eq!(grid.rows().count(), 3);
+    assert_eq!(grid.column(1).collect::<String>(), "beh");
+    assert_eq!(
+        grid.ray(Point2::new(0, 0), Vec2::new(1, 1))
+            .map(|(_, c)| c)
+            .collect::<String>(),
+        "ei"
+    );
+    assert_eq!(
+        grid.ray(Point2::new(1, 1), Vec2::new(0, -1))
+            .collect::<Vec<_>>(),
+        vec![(Point2::new(1, 0), &'b')]
+    );
+    assert_eq!(grid.ray(Point2::new(2, 1), Vec2::new(1, 0)).count(), 0);
+}
+
+#[test]
+fn map_and_position() {
+    let grid = letters();
+    assert_eq!(grid.position(|&c| c == 'f'), Some(Point2::new(2, 1)));
+    let codes = grid.map(|&c| c as u8 - b'a');
+    assert_eq!(codes.to_string(), "012\n345\n678");
+    assert_eq!(
+        Grid::new(2, 1, 0).iter().collect::<Vec<_>>(),
+        vec![(Point2::new(0, 0), &0), (Point2::new(1, 0), &0)]
+    );
+}
+
+#[test]
+fn grid_without_columns() {
+    let grid = Grid::new(0, 2, '.');
+    assert_eq!(grid.rows().collect::<Vec<&[char]>>(), [&[], &[]]);
+    assert_eq!(grid.column(0).count(), 0);
+    assert_eq!(grid.positions().count(), 0);
+
+    let grid = Grid::<char>::from_cells(0, 0, vec![]);
+    assert_eq!(grid.rows().count(), 0);
+    assert_eq!(letters().column(3).count(), 0);
+}
@@ -0,0 +1,64 @@
+use aoc_common::{for_each_group, groups, Group, ParseError};
+
+// (group number, [(line number, text)]) for easy comparison
+fn shape(group: &Group) -> (usize, Vec<(usize, String)>) {
+    let lines = group
+        .lines
+        .iter()
+        .map(|line| (line.index + 1, line.text.to_string()))
+        .collect();
+    (group.number, lines)
+}
+
+fn streamed(input: &str) -> Vec<(usize, Vec<(usize, String)>)> {
+    let mut shapes = vec![];
+    for_each_group(input.as_bytes(), |group| {
+        shapes.push(shape(&group));
+        Ok(())
+    })
+    .unwrap();
+    shapes
+}
+
+#[test]
+fn blank_lines_of_any_kind_only_separate() {
+    let input = "\n1\n2\r\n  \r\n\t\n\n3 \n\n\n";
+    let expected = vec![
+        (1, vec![(2, String::from("1")), (3, String::from("2"))]),
+        (2, vec![(7, String::from("3 "))]),
+    ];
+    assert_eq!(
+        groups(input).map(|g| shape(&g)).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(streamed(input), expected);
+}
+
+#[test]
+fn no_groups_without_content() {
+    for input in ["", "\n", " \r\n\t\n"] {
+        assert_eq!(groups(input).count(), 0, "{:?}", input);
+        assert!(streamed(input).is_empty(), "{:?}", input);
+    }
+}
+
+#[test]
+fn parse_lines_trims_and_names_the_group() {
+    let mut all = groups("1\n 2 \r\n\n3\n4x\n");
+    assert_eq!(all.next().unwrap().parse_lines::<u32>(), Ok(vec![1, 2]));
+    assert_eq!(
+        all.next().unwrap().parse_lines::<u32>(),
+        Err(ParseError::new(5, 1, "4x", "expected u32 in group 2"))
+    );
+}
+
+#[test]
+fn stream_stops_at_the_first_error() {
+    let mut seen = vec![];
+    let result = for_each_group("1\n\nx\n\n3\n".as_bytes(), |group| {
+        seen.push(group.number);
+        group.parse_lines::<u32>().map(|_| ())
+    });
+    assert_eq!(result.unwrap_err().line, 3);
+    assert_eq!(seen, vec![1, 2]);
+}
@@ -0,0 +1,49 @@
+use aoc_common::IntervalSet;
+
+fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+    ranges.iter().map(|&(s, e)| s..=e).collect()
+}
+
+#[test]
+fn insert_coalesces_overlapping_and_adjacent() {
+    let mut s = IntervalSet::new();
+    s.insert(10..=12);
+    s.insert(1..=3);
+    s.insert(5..=6);
+    s.insert(4..=4);
+    let (start, end) = (20, 19);
+    s.insert(start..=end);
+    assert_eq!(s.iter().collect::<Vec<_>>(), vec![1..=6, 10..=12]);
+
+    s.insert(0..=11);
+    assert_eq!(s.iter().collect::<Vec<_>>(), vec![0..=12]);
+    assert_eq!(s, set(&[(4, 4), (10, 12), (0, 11)]));
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(0, 5), (10, 15)]);
+    let b = set(&[(3, 11), (20, 21)]);
+    assert_eq!(a.union(&b), set(&[(0, 15), (20, 21)]));
+    assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 11)]));
+    assert_eq!(a.difference(&b), set(&[(0, 2), (12, 15)]));
+    assert_eq!(b.difference(&a), set(&[(6, 9), (20, 21)]));
+    assert!(a.difference(&a).is_empty());
+    assert_eq!(a.difference(&IntervalSet::new()), a);
+}
+
+#[test]
+fn lengths_gaps_and_queries() {
+    let s = set(&[(-3, -1), (2, 2), (5, 9)]);
+    assert_eq!(s.covered_len(), 9);
+    assert_eq!(s.gaps().collect::<Vec<_>>(), vec![0..=1, 3..=4]);
+
+    assert!(s.contains(-3));
+    assert!(s.contains(2));
+    assert!(!s.contains(0));
+    assert!(!s.contains(10));
+    assert!(s.contains_range(6..=9));
+    assert!(!s.contains_range(1..=2));
+    assert!(s.overlaps(9..=20));
+    assert!(!s.overlaps(3..=4));
+}
@@ -0,0 +1,57 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use aoc_common::{for_each_line, source_lines, ParseError};
+
+fn collect(input: &str) -> Vec<(usize, String)> {
+    let mut lines = vec![];
+    for_each_line(BufReader::with_capacity(2, input.as_bytes()), |line| {
+        lines.push((line.index, line.text.to_string()));
+        Ok(())
+    })
+    .unwrap();
+    lines
+}
+
+#[test]
+fn same_lines_as_source_lines() {
+    for input in ["", "a", "a\n", "a\r\nb\n\nc", "a\rb\n\r\n", "\n\n"] {
+        let expected = source_lines(input)
+            .map(|line| (line.index, line.text.to_string()))
+            .collect::<Vec<(usize, String)>>();
+        assert_eq!(collect(input), expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn stops_at_the_first_error() {
+    let mut seen = 0;
+    let result = for_each_line("1\n2\nx\n4\n".as_bytes(), |line| {
+        seen += 1;
+        line.parse::<u32>(line.text).map(|_| ())
+    });
+    assert_eq!(result.unwrap_err().line, 3);
+    assert_eq!(seen, 3);
+}
+
+struct Failing;
+
+impl Read for Failing {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk on fire"))
+    }
+}
+
+#[test]
+fn read_errors_become_parse_errors() {
+    let reader: &mut dyn BufRead = &mut BufReader::new(Failing);
+    let error: ParseError = for_each_line(reader, |_| Ok(())).unwrap_err();
+    assert_eq!(error.line, 1);
+    assert!(error.message.contains("disk on fire"));
+}
+
+#[test]
+fn whole_input_errors_have_no_position() {
+    let error = ParseError::whole_input("no solution");
+    assert_eq!(error.to_string(), "no solution");
+    assert_eq!(error.render("a\nb\n"), "no solution");
+}
@@ -0,0 +1,64 @@
+use aoc_common::{source_lines, Dir4, Dir8, ParseError, Point2, Vec2};
+
+#[test]
+fn arithmetic() {
+    let p = Point2::new(3, -2);
+    let v = Vec2::new(1, 4);
+    assert_eq!(p + v, Point2::new(4, 2));
+    assert_eq!(p - v, Point2::new(2, -6));
+    assert_eq!(Point2::new(4, 2) - p, v);
+    assert_eq!(v * 3 + v, Vec2::new(4, 16));
+    assert_eq!(-v, Vec2::new(-1, -4));
+
+    let mut q = p;
+    q += v;
+    q -= v * 2;
+    assert_eq!(q, Point2::new(2, -6));
+}
+
+#[test]
+fn distances() {
+    let a = Point2::new(1, 1);
+    let b = Point2::new(4, -1);
+    assert_eq!(a.manhattan(&b), 5);
+    assert_eq!(a.chebyshev(&b), 3);
+    assert_eq!((b - a).manhattan(), 5);
+    assert_eq!((b - a).signum(), Vec2::new(1, -1));
+
+    let c = Point2::new(2u32, 7);
+    assert_eq!(c.manhattan(&Point2::new(5, 3)), 7);
+    assert_eq!(c.chebyshev(&Point2::new(5, 3)), 4);
+}
+
+#[test]
+fn directions() {
+    assert_eq!(Dir4::parse("U"), Some(Dir4::Up));
+    assert_eq!(Dir4::parse(">"), Some(Dir4::Right));
+    assert_eq!(Dir4::parse("x"), None);
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+    assert_eq!(Dir4::Down.delta(), Vec2::new(0, 1));
+    assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+    assert_eq!(
+        Dir8::ALL
+            .iter()
+            .map(|d| d.delta())
+            .fold(Vec2::new(0, 0), |a, b| a + b),
+        Vec2::new(0, 0)
+    );
+}
+
+#[test]
+fn parses_both_forms() {
+    let line = source_lines("498,4 x=-2, y=18").next().unwrap();
+    let (pair, named) = line.text.split_once(' ').unwrap();
+    assert_eq!(Point2::<u32>::parse(&line, pair), Ok(Point2::new(498, 4)));
+    assert_eq!(Point2::<i64>::parse(&line, named), Ok(Point2::new(-2, 18)));
+
+    let line = source_lines("x=1, z=2").next().unwrap();
+    assert_eq!(
+        Point2::<i64>::parse(&line, line.text),
+        Err(ParseError::new(1, 6, "z=2", "expected 'y='"))
+    );
+}
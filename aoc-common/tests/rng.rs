@@ -0,0 +1,31 @@
+use aoc_common::Rng;
+
+#[test]
+fn same_seed_same_sequence() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let xs = (0..8).map(|_| a.next_u64()).collect::<Vec<u64>>();
+    assert_eq!(xs, (0..8).map(|_| b.next_u64()).collect::<Vec<u64>>());
+    assert_ne!(xs, (0..8).map(|_| c.next_u64()).collect::<Vec<u64>>());
+}
+
+#[test]
+fn range_stays_in_bounds_and_hits_both_ends() {
+    let mut rng = Rng::new(7);
+    let values = (0..1000).map(|_| rng.range(-3..=3)).collect::<Vec<i64>>();
+    assert!(values.iter().all(|v| (-3..=3).contains(v)));
+    assert!(values.contains(&-3) && values.contains(&3));
+    assert_eq!(rng.range(5..=5), 5);
+    assert!(rng.index(10) < 10);
+}
+
+#[test]
+fn shuffle_is_a_permutation() {
+    let mut rng = Rng::new(1);
+    let mut items = (0..50).collect::<Vec<u32>>();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..50).collect::<Vec<u32>>());
+    items.sort();
+    assert_eq!(items, (0..50).collect::<Vec<u32>>());
+}
@@ -0,0 +1,47 @@
+use std::{fs, io, path::Path};
+
+use toml::{Table, Value};
+
+pub const FILE_NAME: &str = "answers.toml";
+
+pub struct KnownAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl KnownAnswers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub fn load(path: &Path) -> Result<Option<KnownAnswers>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Unable to read file {}: {}", path.display(), e)),
+    };
+    let table = content
+        .parse::<Table>()
+        .map_err(|e| format!("Invalid answer file {}: {}", path.display(), e))?;
+
+    let answer = |key: &str| match table.get(key) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(Value::Integer(i)) => Ok(Some(i.to_string())),
+        Some(_) => Err(format!(
+            "Unsupported value for '{}' in {}",
+            key,
+            path.display()
+        )),
+    };
+
+    Ok(Some(KnownAnswers {
+        part1: answer("part1")?,
+        part2: answer("part2")?,
+    }))
+}
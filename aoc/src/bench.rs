@@ -0,0 +1,98 @@
+use std::{
+    hint,
+    time::{Duration, Instant},
+};
+
+use crate::registry::Solution;
+
+pub struct DayTiming {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Option<Duration>,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    let mid = samples.len() / 2;
+    if samples.len().is_multiple_of(2) {
+        (samples[mid - 1] + samples[mid]) / 2
+    } else {
+        samples[mid]
+    }
+}
+
+fn measure<T, E>(runs: usize, mut f: impl FnMut() -> Result<T, E>) -> Result<Duration, E> {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = hint::black_box(f()?);
+        samples.push(start.elapsed());
+        drop(result);
+    }
+    Ok(median(samples))
+}
+
+pub fn bench_solution(solution: &Solution, input: &str, runs: usize) -> Result<DayTiming, String> {
+    let parse = match solution.parse {
+        Some(parse) => {
+            Some(measure(runs, || parse(hint::black_box(input))).map_err(|e| e.render(input))?)
+        }
+        None => None,
+    };
+    let part1 = measure(runs, || solution.solve(1, hint::black_box(input), &[]))?;
+    let part2 = measure(runs, || solution.solve(2, hint::black_box(input), &[]))?;
+
+    Ok(DayTiming {
+        year: solution.year,
+        day: solution.day,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn to_markdown(timings: &[DayTiming]) -> String {
+    let mut table = vec![
+        String::from("| Year | Day | Parse (ms) | Part 1 (ms) | Part 2 (ms) |"),
+        String::from("| ---: | --: | ---------: | ----------: | ----------: |"),
+    ];
+    for t in timings {
+        table.push(format!(
+            "| {} | {} | {} | {:.3} | {:.3} |",
+            t.year,
+            t.day,
+            t.parse
+                .map_or(String::from("-"), |p| format!("{:.3}", millis(p))),
+            millis(t.part1),
+            millis(t.part2)
+        ));
+    }
+    table.join("\n")
+}
+
+pub fn to_json(timings: &[DayTiming], runs: usize) -> String {
+    let days = timings
+        .iter()
+        .map(|t| {
+            format!(
+                "    {{ \"year\": {}, \"day\": {}, \"parse_ms\": {}, \"part1_ms\": {:.6}, \"part2_ms\": {:.6} }}",
+                t.year,
+                t.day,
+                t.parse.map_or(String::from("null"), |p| format!("{:.6}", millis(p))),
+                millis(t.part1),
+                millis(t.part2)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",\n");
+    format!(
+        "{{\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}\n",
+        runs, days
+    )
+}
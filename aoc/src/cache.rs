@@ -0,0 +1,184 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{bench::DayTiming, history, remote::Verdict};
+
+pub const DEFAULT_DIR: &str = ".aoc-cache";
+
+pub struct Submission {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let root = env::var("AOC_CACHE_DIR").unwrap_or_else(|_| String::from(DEFAULT_DIR));
+        Self::new(Path::new(&root))
+    }
+
+    pub fn answer_log_path(&self) -> PathBuf {
+        self.root.join(history::FILE_NAME)
+    }
+
+    fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day_{}", day))
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input")
+    }
+
+    fn submissions_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("submissions")
+    }
+
+    fn timing_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("bench")
+    }
+
+    pub fn read_input(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.input_path(year, day)).ok()
+    }
+
+    pub fn store_input(&self, year: u16, day: u8, input: &str) -> Result<(), String> {
+        let dir = self.day_dir(year, day);
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Unable to create directory {}: {}", dir.display(), e))?;
+        let path = self.input_path(year, day);
+        fs::write(&path, input)
+            .map_err(|e| format!("Unable to write file {}: {}", path.display(), e))
+    }
+
+    pub fn submissions(&self, year: u16, day: u8) -> Result<Vec<Submission>, String> {
+        let path = self.submissions_path(year, day);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Ok(vec![]);
+        };
+
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                let invalid = || format!("Invalid entry in {} at line {}", path.display(), i + 1);
+                let mut fields = line.splitn(3, '\t');
+                let part = fields.next().and_then(|p| p.parse::<u8>().ok());
+                let verdict = fields.next().and_then(Verdict::parse);
+                match (part, verdict, fields.next()) {
+                    (Some(part), Some(verdict), Some(answer)) => Ok(Submission {
+                        part,
+                        verdict,
+                        answer: answer.to_string(),
+                    }),
+                    _ => Err(invalid()),
+                }
+            })
+            .collect()
+    }
+
+    pub fn record(&self, year: u16, day: u8, submission: &Submission) -> Result<(), String> {
+        let dir = self.day_dir(year, day);
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Unable to create directory {}: {}", dir.display(), e))?;
+        let path = self.submissions_path(year, day);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Unable to open file {}: {}", path.display(), e))?;
+        writeln!(
+            file,
+            "{}\t{}\t{}",
+            submission.part,
+            submission.verdict.as_str(),
+            submission.answer
+        )
+        .map_err(|e| format!("Unable to write file {}: {}", path.display(), e))
+    }
+
+    // Only the latest `aoc bench` result is kept, as "<step>\t<nanoseconds>" lines
+    pub fn store_timing(&self, timing: &DayTiming) -> Result<(), String> {
+        let dir = self.day_dir(timing.year, timing.day);
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Unable to create directory {}: {}", dir.display(), e))?;
+        let mut content = String::new();
+        if let Some(parse) = timing.parse {
+            content += &format!("parse\t{}\n", parse.as_nanos());
+        }
+        content += &format!("part1\t{}\n", timing.part1.as_nanos());
+        content += &format!("part2\t{}\n", timing.part2.as_nanos());
+        let path = self.timing_path(timing.year, timing.day);
+        fs::write(&path, content)
+            .map_err(|e| format!("Unable to write file {}: {}", path.display(), e))
+    }
+
+    pub fn timing(&self, year: u16, day: u8) -> Result<Option<DayTiming>, String> {
+        let path = self.timing_path(year, day);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Ok(None);
+        };
+
+        let mut timing = DayTiming {
+            year,
+            day,
+            parse: None,
+            part1: Duration::ZERO,
+            part2: Duration::ZERO,
+        };
+        for (i, line) in content.lines().enumerate() {
+            let invalid = || format!("Invalid entry in {} at line {}", path.display(), i + 1);
+            let (step, nanos) = line.split_once('\t').ok_or_else(invalid)?;
+            let duration = Duration::from_nanos(nanos.parse::<u64>().map_err(|_| invalid())?);
+            match step {
+                "parse" => timing.parse = Some(duration),
+                "part1" => timing.part1 = duration,
+                "part2" => timing.part2 = duration,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(Some(timing))
+    }
+}
+
+pub fn known_verdict(history: &[Submission], part: u8, answer: &str) -> Option<Verdict> {
+    let history = history.iter().filter(|s| s.part == part);
+    let value = answer.parse::<i64>().ok();
+
+    for submission in history {
+        if submission.answer == answer {
+            return Some(submission.verdict);
+        }
+        if submission.verdict == Verdict::Correct {
+            return Some(Verdict::Wrong);
+        }
+        let bound = submission.answer.parse::<i64>().ok();
+        match (submission.verdict, value, bound) {
+            (Verdict::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                return Some(Verdict::TooHigh)
+            }
+            (Verdict::TooLow, Some(value), Some(bound)) if value <= bound => {
+                return Some(Verdict::TooLow)
+            }
+            _ => (),
+        }
+    }
+    None
+}
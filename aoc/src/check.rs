@@ -0,0 +1,208 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Answer;
+
+use crate::{
+    answers::{self, KnownAnswers},
+    input,
+    registry::Solution,
+};
+
+pub struct DayInput {
+    pub solution: &'static Solution,
+    pub input: Result<String, String>,
+    pub known: Option<KnownAnswers>,
+}
+
+impl DayInput {
+    pub fn load(solution: &'static Solution) -> Self {
+        let known = answers::load(&solution.day_dir().join(answers::FILE_NAME));
+        match known {
+            Ok(known) => Self {
+                solution,
+                input: input::read(solution, None),
+                known,
+            },
+            Err(e) => Self {
+                solution,
+                input: Err(e),
+                known: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong,
+    Failed,
+    Unchecked,
+}
+
+impl Status {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Status::Correct => "✓",
+            Status::Wrong | Status::Failed => "✗",
+            Status::Unchecked => "-",
+        }
+    }
+}
+
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<Answer, String>,
+    pub expected: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Status::Failed,
+            (Ok(_), None) => Status::Unchecked,
+            (Ok(answer), Some(expected)) if answer.to_string() == *expected => Status::Correct,
+            (Ok(_), Some(_)) => Status::Wrong,
+        }
+    }
+}
+
+fn solve(day: &DayInput, part: u8) -> PartResult {
+    let start = Instant::now();
+    // A panicking solver only fails its own part instead of taking the whole report down
+    let answer = match &day.input {
+        Ok(input) => panic::catch_unwind(AssertUnwindSafe(|| day.solution.solve(part, input, &[])))
+            .unwrap_or_else(|payload| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(format!("panicked: {}", message))
+            }),
+        Err(e) => Err(e.clone()),
+    };
+    PartResult {
+        year: day.solution.year,
+        day: day.solution.day,
+        part,
+        answer,
+        expected: day
+            .known
+            .as_ref()
+            .and_then(|k| k.get(part))
+            .map(String::from),
+        elapsed: start.elapsed(),
+    }
+}
+
+// No more workers than there are jobs, but always at least one
+pub fn worker_count(jobs: usize, threads: usize) -> usize {
+    threads.clamp(1, jobs.max(1))
+}
+
+// Every (day, part) is a job; `threads` workers take the next one until none are left
+pub fn run_parallel(days: &[DayInput], parts: &[u8], threads: usize) -> Vec<PartResult> {
+    let jobs = days
+        .iter()
+        .flat_map(|day| parts.iter().map(move |&part| (day, part)))
+        .collect::<Vec<(&DayInput, u8)>>();
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(jobs.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..worker_count(jobs.len(), threads) {
+            scope.spawn(|| {
+                while let Some(&(day, part)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = solve(day, part);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|r| (r.year, r.day, r.part));
+    results
+}
+
+fn answer_cell(result: &PartResult) -> String {
+    match &result.answer {
+        Ok(Answer::Grid(rows)) => format!(
+            "{}x{} grid",
+            rows.first().map_or(0, |r| r.chars().count()),
+            rows.len()
+        ),
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e.lines().next().unwrap_or("")),
+    }
+}
+
+pub fn to_table(results: &[PartResult]) -> String {
+    let answers = results.iter().map(answer_cell).collect::<Vec<String>>();
+    let width = answers
+        .iter()
+        .map(|a| a.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    let mut table = vec![
+        format!("| Day | Part | {:<width$} |     | Time (ms) |", "Answer"),
+        format!("| --: | ---: | {:-<width$} | :-: | --------: |", ""),
+    ];
+    for (r, answer) in results.iter().zip(&answers) {
+        table.push(format!(
+            "| {:>3} | {:>4} | {:<width$} |  {}  | {:>9.3} |",
+            r.day,
+            r.part,
+            answer,
+            r.status().symbol(),
+            r.elapsed.as_secs_f64() * 1000.0
+        ));
+    }
+    table.join("\n")
+}
+
+pub fn summary(results: &[PartResult], wall: Duration, threads: usize) -> String {
+    let count = |status| results.iter().filter(|r| r.status() == status).count();
+    let mut lines = vec![format!(
+        "{} parts in {:.3} ms on {} thread{}: {} correct, {} wrong, {} failed, {} unchecked",
+        results.len(),
+        wall.as_secs_f64() * 1000.0,
+        threads,
+        if threads == 1 { "" } else { "s" },
+        count(Status::Correct),
+        count(Status::Wrong),
+        count(Status::Failed),
+        count(Status::Unchecked)
+    )];
+    for r in results {
+        match (r.status(), &r.answer) {
+            (Status::Wrong, Ok(answer)) => lines.push(format!(
+                "{} day {} part {}: expected {}, got {}",
+                r.year,
+                r.day,
+                r.part,
+                r.expected.as_deref().unwrap_or(""),
+                answer
+            )),
+            (Status::Failed, Err(e)) => {
+                lines.push(format!("{} day {} part {}: {}", r.year, r.day, r.part, e))
+            }
+            _ => (),
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,300 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, BufRead, Read, Write},
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::output::Record;
+
+pub const FILE_NAME: &str = "answers.log";
+
+// One line per answer: run, unix time, commit, year, day, part, input hash, answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub run: u32,
+    pub time: u64,
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+type Key = (u16, u8, u8, String);
+
+impl Entry {
+    fn key(&self) -> Key {
+        (self.year, self.day, self.part, self.input_hash.clone())
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+fn fnv(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// FNV-1a, which unlike the std hasher is stable across Rust versions
+pub fn input_hash(input: &str) -> String {
+    format!("{:016x}", fnv(FNV_OFFSET, input.as_bytes()))
+}
+
+// Hashes the bytes a streaming solver reads, so its answers are logged under
+// the same input hash as a run that read the whole input at once
+pub struct HashingReader<R> {
+    inner: R,
+    hash: u64,
+}
+
+impl<R: BufRead> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hash: FNV_OFFSET,
+        }
+    }
+
+    // Reads whatever the solver left unread, which is part of the input too
+    pub fn finish(mut self) -> io::Result<String> {
+        io::copy(&mut self, &mut io::sink())?;
+        Ok(format!("{:016x}", self.hash))
+    }
+}
+
+impl<R: BufRead> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hash = fnv(self.hash, &buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    // The buffer is unchanged since the last fill_buf, so this does no I/O
+    fn consume(&mut self, amt: usize) {
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.hash = fnv(self.hash, &buf[..amt.min(buf.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+// Short hash of HEAD, with a '+' if the work tree has changes; '-' outside of git
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if !commit.is_empty() => {
+            let dirty = git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+            format!("{}{}", commit, if dirty { "+" } else { "" })
+        }
+        _ => String::from("-"),
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+fn unescape(field: &str) -> String {
+    let mut answer = String::new();
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            answer.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => answer.push('\n'),
+            Some('t') => answer.push('\t'),
+            Some(other) => answer.push(other),
+            None => answer.push('\\'),
+        }
+    }
+    answer
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("Unable to read file {}: {}", path.display(), e)),
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let invalid = || format!("Invalid entry in {} at line {}", path.display(), i + 1);
+            let fields = line.splitn(8, '\t').collect::<Vec<&str>>();
+            let [run, time, commit, year, day, part, input_hash, answer] = fields[..] else {
+                return Err(invalid());
+            };
+            Ok(Entry {
+                run: run.parse().map_err(|_| invalid())?,
+                time: time.parse().map_err(|_| invalid())?,
+                commit: commit.to_string(),
+                year: year.parse().map_err(|_| invalid())?,
+                day: day.parse().map_err(|_| invalid())?,
+                part: part.parse().map_err(|_| invalid())?,
+                input_hash: input_hash.to_string(),
+                answer: unescape(answer),
+            })
+        })
+        .collect()
+}
+
+// Appends the answers as a new run and returns its number
+pub fn append(path: &Path, commit: &str, answers: &[(String, Record)]) -> Result<u32, String> {
+    let run = load(path)?.iter().map(|e| e.run).max().unwrap_or(0) + 1;
+    if answers.is_empty() {
+        return Ok(run);
+    }
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Unable to create directory {}: {}", dir.display(), e))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Unable to open file {}: {}", path.display(), e))?;
+    let lines = answers
+        .iter()
+        .map(|(input_hash, r)| {
+            format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                run,
+                time,
+                commit,
+                r.year,
+                r.day,
+                r.part,
+                input_hash,
+                escape(&r.answer.to_string())
+            )
+        })
+        .collect::<String>();
+    file.write_all(lines.as_bytes())
+        .map_err(|e| format!("Unable to write file {}: {}", path.display(), e))?;
+    Ok(run)
+}
+
+// A run number, or the latest run recorded at a commit starting with `spec`
+pub fn resolve_run(entries: &[Entry], spec: &str) -> Result<u32, String> {
+    if let Ok(run) = spec.parse::<u32>() {
+        if entries.iter().any(|e| e.run == run) {
+            return Ok(run);
+        }
+        return Err(format!("No recorded run {}", run));
+    }
+    entries
+        .iter()
+        .filter(|e| e.commit.starts_with(spec))
+        .map(|e| e.run)
+        .max()
+        .ok_or(format!("No recorded run at commit '{}'", spec))
+}
+
+pub fn runs(entries: &[Entry]) -> Vec<u32> {
+    let mut runs = entries.iter().map(|e| e.run).collect::<Vec<u32>>();
+    runs.sort();
+    runs.dedup();
+    runs
+}
+
+pub fn commit_of(entries: &[Entry], run: u32) -> &str {
+    entries
+        .iter()
+        .find(|e| e.run == run)
+        .map_or("-", |e| e.commit.as_str())
+}
+
+// The latest answer for every key as of the end of `run`
+fn snapshot(entries: &[Entry], run: u32) -> BTreeMap<Key, &Entry> {
+    let mut answers = BTreeMap::new();
+    for entry in entries.iter().filter(|e| e.run <= run) {
+        answers.insert(entry.key(), entry);
+    }
+    answers
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub before: Option<String>,
+    pub after: String,
+}
+
+// Answers recorded after `from` up to and including `to` that differ from (or
+// are missing in) the state as of `from`
+pub fn diff(entries: &[Entry], from: u32, to: u32) -> Vec<Change> {
+    let before = snapshot(entries, from);
+    snapshot(entries, to)
+        .into_iter()
+        .filter(|(_, entry)| entry.run > from)
+        .filter_map(|(key, entry)| {
+            let previous = before.get(&key).map(|e| e.answer.clone());
+            (previous.as_ref() != Some(&entry.answer)).then(|| Change {
+                year: entry.year,
+                day: entry.day,
+                part: entry.part,
+                input_hash: entry.input_hash.clone(),
+                before: previous,
+                after: entry.answer.clone(),
+            })
+        })
+        .collect()
+}
+
+pub fn render(changes: &[Change]) -> String {
+    changes
+        .iter()
+        .map(|c| {
+            let target = format!("{} day {} part {}", c.year, c.day, c.part);
+            let multi_line = c.after.contains('\n');
+            match &c.before {
+                Some(before) if multi_line || before.contains('\n') => {
+                    format!("{}: changed from\n{}\nto\n{}", target, before, c.after)
+                }
+                Some(before) => format!("{}: {} -> {}", target, before, c.after),
+                None => {
+                    let input = &c.input_hash[..8.min(c.input_hash.len())];
+                    if multi_line {
+                        format!("{}: first answer for input {}\n{}", target, input, c.after)
+                    } else {
+                        format!("{}: {} (first answer for input {})", target, c.after, input)
+                    }
+                }
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
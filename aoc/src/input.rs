@@ -0,0 +1,63 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{cache::Cache, registry::Solution};
+
+pub fn default_path(solution: &Solution) -> PathBuf {
+    solution.day_dir().join("input_p1")
+}
+
+fn missing(solution: &Solution) -> String {
+    format!(
+        "No input for {} day {}, add {} or run: aoc fetch {} {}",
+        solution.year,
+        solution.day,
+        default_path(solution).display(),
+        solution.year,
+        solution.day
+    )
+}
+
+// Where the input comes from when none is given: the day's own input_p1, unless
+// it is missing or still empty as `aoc new` leaves it, then the fetched copy
+pub fn default_source(root: &Path, cache: &Cache, solution: &Solution) -> Result<PathBuf, String> {
+    let local = root.join(default_path(solution));
+    if fs::metadata(&local).is_ok_and(|m| m.len() > 0) {
+        return Ok(local);
+    }
+    let cached = cache.input_path(solution.year, solution.day);
+    if cached.exists() {
+        return Ok(cached);
+    }
+    Err(missing(solution))
+}
+
+pub fn read(solution: &Solution, input: Option<&str>) -> Result<String, String> {
+    let path = match input {
+        Some("-") => {
+            let mut buffer = String::new();
+            io::stdin()
+                .read_to_string(&mut buffer)
+                .map_err(|e| format!("Unable to read stdin: {}", e))?;
+            return Ok(buffer);
+        }
+        Some(path) => PathBuf::from(path),
+        None => default_source(Path::new(""), &Cache::from_env(), solution)?,
+    };
+    fs::read_to_string(&path).map_err(|e| format!("Unable to read file {}: {}", path.display(), e))
+}
+
+// Same lookup as `read`, but the input is read lazily
+pub fn open(solution: &Solution, input: Option<&str>) -> Result<Box<dyn BufRead>, String> {
+    let path = match input {
+        Some("-") => return Ok(Box::new(io::stdin().lock())),
+        Some(path) => PathBuf::from(path),
+        None => default_source(Path::new(""), &Cache::from_env(), solution)?,
+    };
+    let file =
+        File::open(&path).map_err(|e| format!("Unable to read file {}: {}", path.display(), e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
@@ -0,0 +1,13 @@
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod check;
+pub mod history;
+pub mod input;
+pub mod list;
+pub mod output;
+pub mod params;
+pub mod registry;
+pub mod remote;
+pub mod scaffold;
+pub mod visualize;
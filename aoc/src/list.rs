@@ -0,0 +1,192 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::{answers, cache::Cache, registry};
+
+pub struct PartStatus {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub implemented: bool,
+    pub tested: bool,
+    pub known: bool,
+    pub benchmarked: Option<Duration>,
+}
+
+fn numbered_entries(dir: &Path, prefix: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            let number = name.strip_prefix(prefix)?;
+            (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit())).then_some(name)
+        })
+        .collect()
+}
+
+// Registered years plus any year directory that has day crates but no registry yet
+pub fn years(root: &Path) -> Vec<u16> {
+    let mut years = registry::years().collect::<Vec<u16>>();
+    for name in numbered_entries(root, "") {
+        if let Ok(year) = name.parse::<u16>() {
+            if !numbered_entries(&root.join(&name), "day_").is_empty() {
+                years.push(year);
+            }
+        }
+    }
+    years.sort();
+    years.dedup();
+    years
+}
+
+fn days(root: &Path, year: u16) -> Vec<u8> {
+    let mut days = registry::for_year(year).map(|s| s.day).collect::<Vec<u8>>();
+    days.extend(
+        numbered_entries(&root.join(year.to_string()), "day_")
+            .iter()
+            .filter_map(|name| name["day_".len()..].parse::<u8>().ok()),
+    );
+    days.sort();
+    days.dedup();
+    days
+}
+
+// Names of the `#[test]` functions in a test file; other attributes may sit in
+// between, commented-out tests are skipped
+fn test_names(source: &str) -> Vec<&str> {
+    let mut names = vec![];
+    let mut in_test = false;
+    for line in source.lines().map(str::trim) {
+        if line == "#[test]" {
+            in_test = true;
+        } else if let Some(signature) = line.strip_prefix("fn ").filter(|_| in_test) {
+            names.extend(signature.split('(').next());
+            in_test = false;
+        } else if !line.starts_with("#[") {
+            in_test = false;
+        }
+    }
+    names
+}
+
+// A part counts as tested when the day has a test named after it, such as
+// `part1_example`; which functions the tests happen to call does not matter
+fn tested_parts(day_dir: &Path) -> Result<[bool; 2], String> {
+    let mut tested = [false, false];
+    let Ok(entries) = fs::read_dir(day_dir.join("tests")) else {
+        return Ok(tested);
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let source = fs::read_to_string(&path)
+            .map_err(|e| format!("Unable to read file {}: {}", path.display(), e))?;
+        for name in test_names(&source) {
+            for (part, tested) in tested.iter_mut().enumerate() {
+                let prefix = format!("part{}", part + 1);
+                *tested |= name == prefix || name.starts_with(&format!("{}_", prefix));
+            }
+        }
+    }
+    Ok(tested)
+}
+
+pub fn collect(root: &Path, cache: &Cache, year: Option<u16>) -> Result<Vec<PartStatus>, String> {
+    let years = match year {
+        Some(year) => vec![year],
+        None => years(root),
+    };
+
+    let mut statuses = vec![];
+    for year in years {
+        for day in days(root, year) {
+            let implemented = registry::find(year, day).is_some();
+            let day_dir = root.join(year.to_string()).join(format!("day_{}", day));
+            let tested = tested_parts(&day_dir)?;
+            let known = answers::load(&day_dir.join(answers::FILE_NAME))?;
+            let timing = cache.timing(year, day)?;
+
+            for part in [1, 2] {
+                statuses.push(PartStatus {
+                    year,
+                    day,
+                    part,
+                    implemented,
+                    tested: tested[part as usize - 1],
+                    known: known.as_ref().is_some_and(|k| k.get(part).is_some()),
+                    benchmarked: timing.as_ref().map(|t| match part {
+                        1 => t.part1,
+                        _ => t.part2,
+                    }),
+                });
+            }
+        }
+    }
+    Ok(statuses)
+}
+
+fn mark(value: bool) -> &'static str {
+    if value {
+        "✓"
+    } else {
+        "-"
+    }
+}
+
+pub fn to_table(statuses: &[PartStatus]) -> String {
+    let mut table = vec![
+        String::from("| Year | Day | Part | Implemented | Tested | Known answer | Bench (ms) |"),
+        String::from("| ---: | --: | ---: | :---------: | :----: | :----------: | ---------: |"),
+    ];
+    for s in statuses {
+        let bench = s.benchmarked.map_or(String::from("-"), |d| {
+            format!("{:.3}", d.as_secs_f64() * 1000.0)
+        });
+        table.push(format!(
+            "| {} | {:>3} | {:>4} | {:^11} | {:^6} | {:^12} | {:>10} |",
+            s.year,
+            s.day,
+            s.part,
+            mark(s.implemented),
+            mark(s.tested),
+            mark(s.known),
+            bench
+        ));
+    }
+    table.join("\n")
+}
+
+// Printed under the table, since the columns do not explain themselves
+pub const LEGEND: &str = "Tested: a test under tests/ is named partN or partN_*. \
+                          Known answer: answers.toml locks it. Bench: last aoc bench median.";
+
+pub fn summary(statuses: &[PartStatus]) -> String {
+    let mut years = statuses.iter().map(|s| s.year).collect::<Vec<u16>>();
+    years.dedup();
+
+    years
+        .iter()
+        .map(|&year| {
+            let parts = statuses
+                .iter()
+                .filter(|s| s.year == year)
+                .collect::<Vec<&PartStatus>>();
+            let count = |f: fn(&PartStatus) -> bool| parts.iter().filter(|s| f(s)).count();
+            format!(
+                "{}: {} of {} parts implemented, {} tested, {} with a known answer, {} benchmarked",
+                year,
+                count(|s| s.implemented),
+                parts.len(),
+                count(|s| s.tested),
+                count(|s| s.known),
+                count(|s| s.benchmarked.is_some())
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
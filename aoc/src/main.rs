@@ -0,0 +1,783 @@
+use std::{env, fs, path::Path, process, str::FromStr, thread, time::Instant};
+
+use aoc::{
+    bench,
+    cache::Cache,
+    check::{self, DayInput, Status},
+    history, input, list,
+    output::{self, Format, Record},
+    params,
+    registry::{self, Solution},
+    remote::{self, Client, Submitted},
+    scaffold,
+    visualize::{self, Export, FrameFormat, Terminal},
+};
+use aoc_common::Rng;
+
+const USAGE: &str = "Usage: aoc run <year> [day] [--part 1|2] [--input <path>|-] \
+                     [--params <file.toml>] [--param <key>=<value>]... \
+                     [--format text|json|csv] [--stream]
+       aoc run <year> --all [--part 1|2] [--threads <n>]
+       aoc bench <year> [day] [--runs <n>] [--markdown <path>] [--json <path>]
+       aoc new <year> <day>
+       aoc list [year]
+       aoc diff [--from <run|commit>] [--to <run|commit>] [--check]
+       aoc fetch <year> [day] [--server <url>] [--force]
+       aoc submit <year> <day> <part> [answer] [--server <url>]
+       aoc viz <year> <day> [--input <path>|-] [--fps <n>] [--every <n>] \
+                     [--export <dir>] [--format text|pbm|ppm] [--scale <n>]
+       aoc gen <year> <day> [--seed <n>] [--size <n>]";
+
+fn parse_target(positional: &[&String]) -> Result<(u16, Option<u8>), String> {
+    let year = positional
+        .first()
+        .ok_or("Missing year")?
+        .parse::<u16>()
+        .map_err(|_| format!("Invalid year '{}'", positional[0]))?;
+    let day = match positional.get(1) {
+        Some(day) => Some(
+            day.parse::<u8>()
+                .map_err(|_| format!("Invalid day '{}'", day))?,
+        ),
+        None => None,
+    };
+    if positional.len() > 2 {
+        return Err(format!("Unexpected argument '{}'", positional[2]));
+    }
+    Ok((year, day))
+}
+
+struct RunArgs {
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    params_file: Option<String>,
+    overrides: Vec<(String, String)>,
+    format: Format,
+    all: bool,
+    threads: Option<usize>,
+    stream: bool,
+}
+
+impl RunArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = vec![];
+        let mut part = None;
+        let mut input = None;
+        let mut params_file = None;
+        let mut overrides = vec![];
+        let mut format = Format::Text;
+        let mut all = false;
+        let mut threads = None;
+        let mut stream = false;
+        let mut args_iter = args.iter();
+
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--format" | "-f" => {
+                    let value = args_iter.next().ok_or("Missing value for --format")?;
+                    format = Format::parse(value)?;
+                }
+                "--part" | "-p" => {
+                    let value = args_iter.next().ok_or("Missing value for --part")?;
+                    part = match value.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("Invalid part '{}', expected 1 or 2", value)),
+                    };
+                }
+                "--input" | "-i" => {
+                    let value = args_iter.next().ok_or("Missing value for --input")?;
+                    input = Some(value.to_string());
+                }
+                "--params" => {
+                    let value = args_iter.next().ok_or("Missing value for --params")?;
+                    params_file = Some(value.to_string());
+                }
+                "--param" => {
+                    let value = args_iter.next().ok_or("Missing value for --param")?;
+                    overrides.push(params::parse_override(value)?);
+                }
+                "--all" => all = true,
+                "--stream" => stream = true,
+                "--threads" => {
+                    let value = args_iter.next().ok_or("Missing value for --threads")?;
+                    threads = Some(parse_positive("--threads", value)?);
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let (year, day) = parse_target(&positional)?;
+        if input.is_some() && day.is_none() {
+            return Err(String::from("--input requires a day"));
+        }
+        if (params_file.is_some() || !overrides.is_empty()) && day.is_none() {
+            return Err(String::from("--params and --param require a day"));
+        }
+        if all && (day.is_some() || format != Format::Text) {
+            return Err(String::from(
+                "--all runs every day and cannot be combined with a day or --format",
+            ));
+        }
+        if threads.is_some() && !all {
+            return Err(String::from("--threads requires --all"));
+        }
+        if stream && day.is_none() {
+            return Err(String::from("--stream requires a day"));
+        }
+
+        Ok(Self {
+            year,
+            day,
+            part,
+            input,
+            params_file,
+            overrides,
+            format,
+            all,
+            threads,
+            stream,
+        })
+    }
+
+    fn collect_overrides(&self) -> Result<Vec<(String, String)>, String> {
+        let mut overrides = match &self.params_file {
+            Some(path) => params::load_file(Path::new(path))?,
+            None => vec![],
+        };
+        overrides.extend(self.overrides.iter().cloned());
+        Ok(overrides)
+    }
+}
+
+fn run_solution(
+    solution: &Solution,
+    part: Option<u8>,
+    input: &str,
+    overrides: &[(String, String)],
+) -> Result<Vec<Record>, String> {
+    [1, 2]
+        .into_iter()
+        .filter(|p| part.is_none() || part == Some(*p))
+        .map(|p| {
+            Ok(Record {
+                year: solution.year,
+                day: solution.day,
+                part: p,
+                answer: solution.solve(p, input, overrides)?,
+            })
+        })
+        .collect()
+}
+
+// Both parts come out of a single pass over the input, hashed on the way
+fn stream_solution(
+    solution: &Solution,
+    part: Option<u8>,
+    input: Option<&str>,
+    overrides: &[(String, String)],
+) -> Result<(String, Vec<Record>), String> {
+    let stream = solution.stream.ok_or(format!(
+        "{} day {} has no streaming mode",
+        solution.year, solution.day
+    ))?;
+    let mut reader = history::HashingReader::new(input::open(solution, input)?);
+    let answers = stream(&mut reader, overrides)?;
+    let input_hash = reader
+        .finish()
+        .map_err(|e| format!("Unable to read input: {}", e))?;
+
+    let records = [1, 2]
+        .into_iter()
+        .zip(answers)
+        .filter(|(p, _)| part.is_none() || part == Some(*p))
+        .map(|(p, answer)| Record {
+            year: solution.year,
+            day: solution.day,
+            part: p,
+            answer,
+        })
+        .collect();
+    Ok((input_hash, records))
+}
+
+fn log_answers(answers: &[(String, Record)]) -> Result<(), String> {
+    let path = Cache::from_env().answer_log_path();
+    history::append(&path, &history::current_commit(), answers).map(|_| ())
+}
+
+fn run_all(args: &RunArgs) -> Result<(), String> {
+    let days = registry::for_year(args.year)
+        .map(DayInput::load)
+        .collect::<Vec<DayInput>>();
+    if days.is_empty() {
+        return Err(format!("No solutions registered for {}", args.year));
+    }
+    let threads = args.threads.unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let start = Instant::now();
+    let results = check::run_parallel(&days, &parts, threads);
+    let wall = start.elapsed();
+
+    println!("{}", check::to_table(&results));
+    let workers = check::worker_count(days.len() * parts.len(), threads);
+    println!("\n{}", check::summary(&results, wall, workers));
+    let answers = results
+        .iter()
+        .filter_map(|r| {
+            let day = days
+                .iter()
+                .find(|d| (d.solution.year, d.solution.day) == (r.year, r.day))?;
+            let record = Record {
+                year: r.year,
+                day: r.day,
+                part: r.part,
+                answer: r.answer.clone().ok()?,
+            };
+            Some((history::input_hash(day.input.as_ref().ok()?), record))
+        })
+        .collect::<Vec<(String, Record)>>();
+    log_answers(&answers)?;
+    let failed = results
+        .iter()
+        .filter(|r| matches!(r.status(), Status::Wrong | Status::Failed))
+        .count();
+    if failed > 0 {
+        return Err(format!("{} of {} parts failed", failed, results.len()));
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let args = RunArgs::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    if args.all {
+        return run_all(&args);
+    }
+
+    let (solutions, overrides): (Vec<&Solution>, _) = match args.day {
+        Some(day) => {
+            let solution = registry::find(args.year, day).ok_or(format!(
+                "No solution registered for {} day {}",
+                args.year, day
+            ))?;
+            (vec![solution], args.collect_overrides()?)
+        }
+        None => (registry::for_year(args.year).collect(), vec![]),
+    };
+    if solutions.is_empty() {
+        return Err(format!("No solutions registered for {}", args.year));
+    }
+
+    let mut records = vec![];
+    let mut answers = vec![];
+    for solution in solutions {
+        let (input_hash, day_records) = if args.stream {
+            stream_solution(solution, args.part, args.input.as_deref(), &overrides)?
+        } else {
+            let input = input::read(solution, args.input.as_deref())?;
+            let day_records = run_solution(solution, args.part, &input, &overrides)?;
+            (history::input_hash(&input), day_records)
+        };
+        // Answers computed with overridden parameters are not comparable between runs
+        if overrides.is_empty() {
+            answers.extend(day_records.iter().map(|r| (input_hash.clone(), r.clone())));
+        }
+        if args.format == Format::Text {
+            println!("{}", output::render(args.format, &day_records));
+        } else {
+            records.extend(day_records);
+        }
+    }
+    if args.format != Format::Text {
+        println!("{}", output::render(args.format, &records));
+    }
+    log_answers(&answers)
+}
+
+struct BenchArgs {
+    year: u16,
+    day: Option<u8>,
+    runs: usize,
+    markdown: Option<String>,
+    json: Option<String>,
+}
+
+impl BenchArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = vec![];
+        let mut runs = 5;
+        let mut markdown = None;
+        let mut json = None;
+        let mut args_iter = args.iter();
+
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--runs" | "-n" => {
+                    let value = args_iter.next().ok_or("Missing value for --runs")?;
+                    runs = match value.parse::<usize>() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("Invalid number of runs '{}'", value)),
+                    };
+                }
+                "--markdown" => {
+                    let value = args_iter.next().ok_or("Missing value for --markdown")?;
+                    markdown = Some(value.to_string());
+                }
+                "--json" => {
+                    let value = args_iter.next().ok_or("Missing value for --json")?;
+                    json = Some(value.to_string());
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let (year, day) = parse_target(&positional)?;
+
+        Ok(Self {
+            year,
+            day,
+            runs,
+            markdown,
+            json,
+        })
+    }
+}
+
+fn write_file(path: &str, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("Unable to write file {}: {}", path, e))
+}
+
+fn run_bench(args: &[String]) -> Result<(), String> {
+    let args = BenchArgs::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+
+    let solutions: Vec<&Solution> = match args.day {
+        Some(day) => vec![registry::find(args.year, day).ok_or(format!(
+            "No solution registered for {} day {}",
+            args.year, day
+        ))?],
+        None => registry::for_year(args.year).collect(),
+    };
+    if solutions.is_empty() {
+        return Err(format!("No solutions registered for {}", args.year));
+    }
+
+    let cache = Cache::from_env();
+    let mut timings = vec![];
+    for solution in solutions {
+        let input = input::read(solution, None)?;
+        eprintln!("Benchmarking {} day {}...", solution.year, solution.day);
+        let timing = bench::bench_solution(solution, &input, args.runs)?;
+        cache.store_timing(&timing)?;
+        timings.push(timing);
+    }
+
+    let table = bench::to_markdown(&timings);
+    println!("{}", table);
+    if let Some(path) = &args.markdown {
+        write_file(path, &(table + "\n"))?;
+    }
+    if let Some(path) = &args.json {
+        write_file(path, &bench::to_json(&timings, args.runs))?;
+    }
+    Ok(())
+}
+
+fn run_new(args: &[String]) -> Result<(), String> {
+    let positional = args.iter().collect::<Vec<&String>>();
+    let (year, day) = match parse_target(&positional) {
+        Ok((year, Some(day))) => (year, day),
+        Ok((_, None)) => return Err(format!("Missing day\n{}", USAGE)),
+        Err(e) => return Err(format!("{}\n{}", e, USAGE)),
+    };
+
+    for path in scaffold::create_day(Path::new("."), year, day)? {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "Add the puzzle input to {}/day_{}/input_p1 and run: cargo run --release -p aoc -- run {} {}",
+        year, day, year, day
+    );
+    Ok(())
+}
+
+fn run_list(args: &[String]) -> Result<(), String> {
+    let year = match args {
+        [] => None,
+        [year] => Some(
+            year.parse::<u16>()
+                .map_err(|_| format!("Invalid year '{}'\n{}", year, USAGE))?,
+        ),
+        _ => return Err(format!("Unexpected argument '{}'\n{}", args[1], USAGE)),
+    };
+
+    let statuses = list::collect(Path::new("."), &Cache::from_env(), year)?;
+    if statuses.is_empty() {
+        return Err(match year {
+            Some(year) => format!("No days found for {}", year),
+            None => String::from("No days found"),
+        });
+    }
+    println!("{}", list::to_table(&statuses));
+    println!("\n{}", list::LEGEND);
+    println!("\n{}", list::summary(&statuses));
+    Ok(())
+}
+
+fn run_diff(args: &[String]) -> Result<(), String> {
+    let mut from = None;
+    let mut to = None;
+    let mut check = false;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--from" => from = Some(args_iter.next().ok_or("Missing value for --from")?),
+            "--to" => to = Some(args_iter.next().ok_or("Missing value for --to")?),
+            _ => return Err(format!("Unexpected argument '{}'\n{}", arg, USAGE)),
+        }
+    }
+
+    let path = Cache::from_env().answer_log_path();
+    let entries = history::load(&path)?;
+    let runs = history::runs(&entries);
+    let to = match to {
+        Some(spec) => history::resolve_run(&entries, spec)?,
+        None => *runs.last().ok_or(format!(
+            "No answers recorded in {} yet, run: aoc run <year>",
+            path.display()
+        ))?,
+    };
+    let from = match from {
+        Some(spec) => history::resolve_run(&entries, spec)?,
+        None => runs
+            .iter()
+            .rev()
+            .find(|&&run| run < to)
+            .copied()
+            .ok_or(format!("Run {} is the first recorded run", to))?,
+    };
+
+    let changes = history::diff(&entries, from, to);
+    println!(
+        "Run {} ({}) -> run {} ({})",
+        from,
+        history::commit_of(&entries, from),
+        to,
+        history::commit_of(&entries, to)
+    );
+    if changes.is_empty() {
+        println!("No answers changed");
+        return Ok(());
+    }
+    println!("{}", history::render(&changes));
+    let changed = changes.iter().filter(|c| c.before.is_some()).count();
+    if check && changed > 0 {
+        return Err(format!(
+            "{} answer{} changed",
+            changed,
+            if changed == 1 { "" } else { "s" }
+        ));
+    }
+    Ok(())
+}
+
+struct RemoteArgs {
+    positional: Vec<String>,
+    server: String,
+    force: bool,
+}
+
+impl RemoteArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = vec![];
+        let mut server = remote::server_from_env();
+        let mut force = false;
+        let mut args_iter = args.iter();
+
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--server" => {
+                    let value = args_iter.next().ok_or("Missing value for --server")?;
+                    server = value.to_string();
+                }
+                "--force" => force = true,
+                _ if arg.starts_with('-') && arg.parse::<i64>().is_err() => {
+                    return Err(format!("Unknown option '{}'", arg))
+                }
+                _ => positional.push(arg.to_string()),
+            }
+        }
+
+        Ok(Self {
+            positional,
+            server,
+            force,
+        })
+    }
+
+    fn client(&self) -> Client {
+        Client::new(&self.server, remote::session_from_env())
+    }
+}
+
+fn run_fetch(args: &[String]) -> Result<(), String> {
+    let args = RemoteArgs::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    let positional = args.positional.iter().collect::<Vec<&String>>();
+    let (year, day) = parse_target(&positional).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => registry::for_year(year).map(|s| s.day).collect(),
+    };
+    if days.is_empty() {
+        return Err(format!("No solutions registered for {}", year));
+    }
+
+    let client = args.client();
+    let cache = Cache::from_env();
+    for day in days {
+        if remote::fetch(&client, &cache, year, day, args.force)? {
+            println!("Fetched {}", cache.input_path(year, day).display());
+        } else {
+            println!("Cached {}", cache.input_path(year, day).display());
+        }
+    }
+    Ok(())
+}
+
+fn run_submit(args: &[String]) -> Result<(), String> {
+    let args = RemoteArgs::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    let positional = args.positional.iter().collect::<Vec<&String>>();
+    if positional.len() < 3 || positional.len() > 4 {
+        return Err(format!(
+            "Expected year, day, part and an optional answer\n{}",
+            USAGE
+        ));
+    }
+    let (year, day) = parse_target(&positional[..2])?;
+    let day = day.ok_or("Missing day")?;
+    let part = match positional[2].as_str() {
+        "1" => 1,
+        "2" => 2,
+        value => return Err(format!("Invalid part '{}', expected 1 or 2", value)),
+    };
+
+    let answer = match positional.get(3) {
+        Some(answer) => answer.to_string(),
+        None => {
+            let solution = registry::find(year, day)
+                .ok_or(format!("No solution registered for {} day {}", year, day))?;
+            let input = input::read(solution, None)?;
+            solution.solve(part, &input, &[])?.to_string()
+        }
+    };
+
+    let cache = Cache::from_env();
+    match remote::submit(&args.client(), &cache, year, day, part, &answer)? {
+        Submitted::Known(verdict) => println!(
+            "{} day {} part {}: {} is {} (known, not submitted)",
+            year, day, part, answer, verdict
+        ),
+        Submitted::New(verdict) => println!(
+            "{} day {} part {}: {} is {}",
+            year, day, part, answer, verdict
+        ),
+    }
+    Ok(())
+}
+
+struct VizArgs {
+    year: u16,
+    day: u8,
+    input: Option<String>,
+    fps: u32,
+    every: usize,
+    export: Option<String>,
+    format: FrameFormat,
+    scale: usize,
+}
+
+// Out of range values fail to parse, so they are rejected rather than truncated
+fn parse_positive<T: FromStr + PartialOrd + Default>(
+    option: &str,
+    value: &str,
+) -> Result<T, String> {
+    match value.parse::<T>() {
+        Ok(n) if n > T::default() => Ok(n),
+        _ => Err(format!("Invalid value '{}' for {}", value, option)),
+    }
+}
+
+impl VizArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = vec![];
+        let mut input = None;
+        let mut fps = 20;
+        let mut every = 1;
+        let mut export = None;
+        let mut format = FrameFormat::Text;
+        let mut scale = 4;
+        let mut args_iter = args.iter();
+
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let value = args_iter.next().ok_or("Missing value for --input")?;
+                    input = Some(value.to_string());
+                }
+                "--fps" => {
+                    let value = args_iter.next().ok_or("Missing value for --fps")?;
+                    fps = parse_positive("--fps", value)?;
+                }
+                "--every" => {
+                    let value = args_iter.next().ok_or("Missing value for --every")?;
+                    every = parse_positive("--every", value)?;
+                }
+                "--export" => {
+                    let value = args_iter.next().ok_or("Missing value for --export")?;
+                    export = Some(value.to_string());
+                }
+                "--format" | "-f" => {
+                    let value = args_iter.next().ok_or("Missing value for --format")?;
+                    format = FrameFormat::parse(value)?;
+                }
+                "--scale" => {
+                    let value = args_iter.next().ok_or("Missing value for --scale")?;
+                    scale = parse_positive("--scale", value)?;
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let (year, day) = parse_target(&positional)?;
+        Ok(Self {
+            year,
+            day: day.ok_or("Missing day")?,
+            input,
+            fps,
+            every,
+            export,
+            format,
+            scale,
+        })
+    }
+}
+
+fn run_viz(args: &[String]) -> Result<(), String> {
+    let args = VizArgs::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    let solution = registry::find(args.year, args.day).ok_or(format!(
+        "No solution registered for {} day {}",
+        args.year, args.day
+    ))?;
+    let visualizer = solution.visualize.ok_or(format!(
+        "No visualisation available for {} day {}",
+        args.year, args.day
+    ))?;
+    let input = input::read(solution, args.input.as_deref())?;
+
+    match &args.export {
+        Some(dir) => {
+            let mut export = Export::new(Path::new(dir), args.format, args.scale)?;
+            visualize::play(visualizer, &input, args.every, &mut export)?;
+            println!("Wrote {} frames to {}", export.written, dir);
+        }
+        None => {
+            let mut terminal = Terminal::new(std::io::stdout().lock(), args.fps);
+            terminal.start()?;
+            let result = visualize::play(visualizer, &input, args.every, &mut terminal);
+            terminal.finish()?;
+            result?;
+        }
+    }
+    Ok(())
+}
+
+struct GenArgs {
+    year: u16,
+    day: u8,
+    seed: u64,
+    size: usize,
+}
+
+impl GenArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = vec![];
+        let mut seed = 0;
+        let mut size = 50;
+        let mut args_iter = args.iter();
+
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    let value = args_iter.next().ok_or("Missing value for --seed")?;
+                    seed = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("Invalid value '{}' for --seed", value))?;
+                }
+                "--size" => {
+                    let value = args_iter.next().ok_or("Missing value for --size")?;
+                    size = parse_positive("--size", value)?;
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let (year, day) = parse_target(&positional)?;
+        Ok(Self {
+            year,
+            day: day.ok_or("Missing day")?,
+            seed,
+            size,
+        })
+    }
+}
+
+fn run_gen(args: &[String]) -> Result<(), String> {
+    let args = GenArgs::parse(args).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    let solution = registry::find(args.year, args.day).ok_or(format!(
+        "No solution registered for {} day {}",
+        args.year, args.day
+    ))?;
+    let generate = solution.generate.ok_or(format!(
+        "No input generator available for {} day {}",
+        args.year, args.day
+    ))?;
+
+    print!("{}", generate(&mut Rng::new(args.seed), args.size)?);
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => run_bench(&args[1..]),
+        Some("new") => run_new(&args[1..]),
+        Some("list") => run_list(&args[1..]),
+        Some("diff") => run_diff(&args[1..]),
+        Some("fetch") => run_fetch(&args[1..]),
+        Some("submit") => run_submit(&args[1..]),
+        Some("viz") => run_viz(&args[1..]),
+        Some("gen") => run_gen(&args[1..]),
+        _ => Err(format!("Missing or unknown command\n{}", USAGE)),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        process::exit(2);
+    }
+}
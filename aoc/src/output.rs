@@ -0,0 +1,129 @@
+use aoc_common::Answer;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Invalid format '{}', expected text, json or csv",
+                value
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+}
+
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        Format::Text => text(records),
+        Format::Json => json(records),
+        Format::Csv => csv(records),
+    }
+}
+
+fn text(records: &[Record]) -> String {
+    let mut lines = vec![];
+    let mut current_day = None;
+
+    for record in records {
+        if current_day != Some((record.year, record.day)) {
+            current_day = Some((record.year, record.day));
+            lines.push(format!("--- {} Day {} ---", record.year, record.day));
+        }
+        match &record.answer {
+            Answer::Grid(_) => lines.push(format!("Part {}:\n{}", record.part, record.answer)),
+            answer => lines.push(format!("Part {}: {}", record.part, answer)),
+        }
+    }
+    lines.join("\n")
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(i) => i.to_string(),
+        Answer::Text(s) => json_string(s),
+        Answer::Grid(rows) => format!(
+            "[{}]",
+            rows.iter()
+                .map(|row| json_string(row))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn json(records: &[Record]) -> String {
+    let entries = records
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{ \"year\": {}, \"day\": {}, \"part\": {}, \"kind\": \"{}\", \"answer\": {} }}",
+                r.year,
+                r.day,
+                r.part,
+                r.answer.kind(),
+                json_answer(&r.answer)
+            )
+        })
+        .collect::<Vec<String>>();
+    if entries.is_empty() {
+        return String::from("[]");
+    }
+    format!("[\n{}\n]", entries.join(",\n"))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut lines = vec![String::from("year,day,part,kind,answer")];
+    for r in records {
+        lines.push(format!(
+            "{},{},{},{},{}",
+            r.year,
+            r.day,
+            r.part,
+            r.answer.kind(),
+            csv_field(&r.answer.to_string())
+        ));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,35 @@
+use std::{fs, path::Path};
+
+use toml::{Table, Value};
+
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("Invalid parameter '{}', expected key=value", arg)),
+    }
+}
+
+pub fn load_file(path: &Path) -> Result<Vec<(String, String)>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read file {}: {}", path.display(), e))?;
+    let table = content
+        .parse::<Table>()
+        .map_err(|e| format!("Invalid parameter file {}: {}", path.display(), e))?;
+
+    table
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => Ok((key, s)),
+            Value::Integer(i) => Ok((key, i.to_string())),
+            Value::Float(f) => Ok((key, f.to_string())),
+            Value::Boolean(b) => Ok((key, b.to_string())),
+            _ => Err(format!(
+                "Unsupported value for parameter '{}' in {}",
+                key,
+                path.display()
+            )),
+        })
+        .collect()
+}
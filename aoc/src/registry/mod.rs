@@ -0,0 +1,96 @@
+use std::{fmt::Debug, io::BufRead, path::PathBuf};
+
+use aoc_common::{Answer, Grid, ParseError, Rng};
+
+mod y2022;
+
+pub type Overrides = [(String, String)];
+pub type Parser = fn(&str) -> Result<(), ParseError>;
+pub type Visualizer = fn(&str, &mut dyn FnMut(&Grid<char>)) -> Result<(), ParseError>;
+pub type Generator = fn(&mut Rng, usize) -> Result<String, String>;
+pub type Streamer = fn(&mut dyn BufRead, &Overrides) -> Result<[Answer; 2], String>;
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Option<Parser>,
+    pub visualize: Option<Visualizer>,
+    pub generate: Option<Generator>,
+    pub stream: Option<Streamer>,
+    pub part1: fn(&str, &Overrides) -> Result<Answer, String>,
+    pub part2: fn(&str, &Overrides) -> Result<Answer, String>,
+}
+
+impl Solution {
+    pub fn day_dir(&self) -> PathBuf {
+        PathBuf::from(self.year.to_string()).join(format!("day_{}", self.day))
+    }
+
+    pub fn solve(&self, part: u8, input: &str, overrides: &Overrides) -> Result<Answer, String> {
+        match part {
+            1 => (self.part1)(input, overrides),
+            2 => (self.part2)(input, overrides),
+            _ => Err(format!("Invalid part {}", part)),
+        }
+    }
+}
+
+fn no_params(overrides: &Overrides) -> Result<(), String> {
+    match overrides.first() {
+        Some((key, _)) => Err(format!("Unknown parameter '{}'", key)),
+        None => Ok(()),
+    }
+}
+
+fn params<P: Default>(
+    overrides: &Overrides,
+    set: fn(&mut P, &str, &str) -> Result<(), String>,
+) -> Result<P, String> {
+    let mut params = P::default();
+    for (key, value) in overrides {
+        set(&mut params, key, value)?;
+    }
+    Ok(params)
+}
+
+fn answer<T: Into<Answer>>(input: &str, result: Result<T, ParseError>) -> Result<Answer, String> {
+    result.map(Into::into).map_err(|e| e.render(input))
+}
+
+// There is no input text to point into, so errors only carry line and column
+fn streamed<A: Into<Answer>, B: Into<Answer>>(
+    result: Result<(A, B), ParseError>,
+) -> Result<[Answer; 2], String> {
+    result
+        .map(|(a, b)| [a.into(), b.into()])
+        .map_err(|e| e.to_string())
+}
+
+fn single_or_list<T: Into<Answer> + Debug + Copy>(values: Vec<T>) -> Answer {
+    match values[..] {
+        [value] => value.into(),
+        _ => Answer::Text(format!("{:?}", values)),
+    }
+}
+
+// One registry per year directory, kept in year order
+pub const YEARS: &[(u16, &[Solution])] = &[(2022, y2022::SOLUTIONS)];
+
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS.iter().map(|(year, _)| *year)
+}
+
+pub fn all() -> impl Iterator<Item = &'static Solution> {
+    YEARS.iter().flat_map(|(_, solutions)| solutions.iter())
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static Solution> {
+    YEARS
+        .iter()
+        .filter(move |(y, _)| *y == year)
+        .flat_map(|(_, solutions)| solutions.iter())
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    for_year(year).find(|s| s.day == day)
+}
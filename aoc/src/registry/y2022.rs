@@ -0,0 +1,301 @@
+use aoc_common::{Answer, Visualize};
+
+use day_2::optimize::{self, Interpretation};
+
+use super::{answer, no_params, params, single_or_list, streamed, Solution};
+
+// With `optimize = true` each part reports the letter assignments for the
+// reading of the second column it normally scores
+fn day_2_optimized(
+    input: &str,
+    params: &day_2::Params,
+    interpretation: Interpretation,
+) -> Result<Answer, String> {
+    let mappings = optimize::optimize_as(input, &params.rules()?, interpretation);
+    answer(
+        input,
+        mappings.map(|m| Answer::grid(optimize::to_text(&m).lines())),
+    )
+}
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution {
+        year: 2022,
+        day: 1,
+        parse: Some(|input| day_1::parse_elves(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_1::generate(rng, size))),
+        stream: Some(|reader, o| {
+            streamed(day_1::solve_stream_with(
+                reader,
+                &params(o, day_1::Params::set)?,
+            ))
+        }),
+        part1: |input, o| {
+            params(o, day_1::Params::set).and_then(|_| answer(input, day_1::part1(input)))
+        },
+        part2: |input, o| {
+            answer(
+                input,
+                day_1::part2_with(input, &params(o, day_1::Params::set)?),
+            )
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 2,
+        parse: Some(|input| day_2::parse_games(input, &day_2::Rules::default(), false).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_2::generate(rng, size))),
+        stream: Some(|reader, o| {
+            let params = params(o, day_2::Params::set)?;
+            if params.optimize {
+                return Err(String::from(
+                    "The optimizer needs the whole input, not --stream",
+                ));
+            }
+            streamed(day_2::solve_stream_with(reader, &params.rules()?))
+        }),
+        part1: |input, o| {
+            let params = params(o, day_2::Params::set)?;
+            if params.optimize {
+                return day_2_optimized(input, &params, Interpretation::ITEMS);
+            }
+            answer(input, day_2::part1_with(input, &params.rules()?))
+        },
+        part2: |input, o| {
+            let params = params(o, day_2::Params::set)?;
+            if params.optimize {
+                return day_2_optimized(input, &params, Interpretation::OUTCOMES);
+            }
+            answer(input, day_2::part2_with(input, &params.rules()?))
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 3,
+        parse: Some(|input| day_3::parse_backpacks(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_3::generate(rng, size))),
+        stream: Some(|reader, o| no_params(o).and_then(|_| streamed(day_3::solve_stream(reader)))),
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_3::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_3::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 4,
+        parse: Some(|input| day_4::parse_pairs(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_4::generate(rng, size))),
+        stream: Some(|reader, o| no_params(o).and_then(|_| streamed(day_4::solve_stream(reader)))),
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_4::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_4::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 5,
+        parse: Some(|input| day_5::parse_procedure(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_5::generate(rng, size))),
+        stream: None,
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_5::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_5::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 6,
+        parse: Some(|input| day_6::parse_data_streams(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_6::generate(rng, size))),
+        stream: Some(|reader, o| {
+            no_params(o).and_then(|_| {
+                streamed(
+                    day_6::solve_stream(reader)
+                        .map(|(p1, p2)| (single_or_list(p1), single_or_list(p2))),
+                )
+            })
+        }),
+        part1: |input, o| {
+            no_params(o).and_then(|_| answer(input, day_6::part1(input).map(single_or_list)))
+        },
+        part2: |input, o| {
+            no_params(o).and_then(|_| answer(input, day_6::part2(input).map(single_or_list)))
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 7,
+        parse: Some(|input| day_7::evaluate_dirs(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_7::generate(rng, size))),
+        stream: None,
+        part1: |input, o| {
+            answer(
+                input,
+                day_7::part1_with(input, &params(o, day_7::Params::set)?),
+            )
+        },
+        part2: |input, o| {
+            answer(
+                input,
+                day_7::part2_with(input, &params(o, day_7::Params::set)?),
+            )
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 8,
+        parse: Some(|input| day_8::parse_trees(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_8::generate(rng, size))),
+        stream: None,
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_8::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_8::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 9,
+        parse: Some(|input| day_9::parse_moves(input).map(|_| ())),
+        visualize: Some(|input, frame| {
+            day_9::Rope::from_input(input, 10).map(|mut rope| rope.visualize(frame))
+        }),
+        generate: Some(|rng, size| Ok(day_9::generate(rng, size))),
+        stream: Some(|reader, o| no_params(o).and_then(|_| streamed(day_9::solve_stream(reader)))),
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_9::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_9::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 10,
+        parse: Some(|input| day_10::parse_program(input).map(|_| ())),
+        visualize: Some(|input, frame| {
+            day_10::Crt::from_input(input).map(|mut crt| crt.visualize(frame))
+        }),
+        generate: Some(|rng, size| Ok(day_10::generate(rng, size))),
+        stream: Some(|reader, o| {
+            no_params(o).and_then(|_| {
+                streamed(
+                    day_10::solve_stream(reader).map(|(p1, p2)| (p1, Answer::grid(p2.lines()))),
+                )
+            })
+        }),
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_10::part1(input))),
+        part2: |input, o| {
+            no_params(o)
+                .and_then(|_| answer(input, day_10::part2(input).map(|s| Answer::grid(s.lines()))))
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 11,
+        parse: Some(|input| day_11::Game::from_input(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_11::generate(rng, size))),
+        stream: None,
+        part1: |input, o| {
+            answer(
+                input,
+                day_11::part1_with(input, &params(o, day_11::Params::set)?),
+            )
+        },
+        part2: |input, o| {
+            answer(
+                input,
+                day_11::part2_with(input, &params(o, day_11::Params::set)?),
+            )
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 12,
+        parse: Some(|input| day_12::Map::from_input(input).map(|_| ())),
+        visualize: Some(|input, frame| {
+            day_12::Map::from_input(input).map(|mut map| map.visualize(frame))
+        }),
+        generate: Some(|rng, size| Ok(day_12::generate(rng, size))),
+        stream: None,
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_12::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_12::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 13,
+        parse: Some(|input| day_13::parse_pairs(input).map(|_| ())),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_13::generate(rng, size))),
+        stream: None,
+        part1: |input, o| no_params(o).and_then(|_| answer(input, day_13::part1(input))),
+        part2: |input, o| no_params(o).and_then(|_| answer(input, day_13::part2(input))),
+    },
+    Solution {
+        year: 2022,
+        day: 14,
+        parse: Some(|input| day_14::build_cave(input, &day_14::Params::default()).map(|_| ())),
+        visualize: Some(|input, frame| {
+            day_14::build_cave(input, &day_14::Params::default())
+                .map(|mut cave| cave.visualize(frame))
+        }),
+        generate: Some(|rng, size| Ok(day_14::generate(rng, size))),
+        stream: None,
+        part1: |input, o| {
+            answer(
+                input,
+                day_14::part1_with(input, &params(o, day_14::Params::set)?),
+            )
+        },
+        part2: |input, o| {
+            answer(
+                input,
+                day_14::part2_with(input, &params(o, day_14::Params::set)?),
+            )
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 15,
+        parse: Some(|input| day_15::parse_markers(input).map(|_| ())),
+        visualize: None,
+        generate: Some(day_15::generate),
+        stream: Some(|reader, o| {
+            streamed(day_15::solve_stream_with(
+                reader,
+                &params(o, day_15::Params::set)?,
+            ))
+        }),
+        part1: |input, o| {
+            answer(
+                input,
+                day_15::part1_with(input, &params(o, day_15::Params::set)?),
+            )
+        },
+        part2: |input, o| {
+            answer(
+                input,
+                day_15::part2_with(input, &params(o, day_15::Params::set)?),
+            )
+        },
+    },
+    Solution {
+        year: 2022,
+        day: 16,
+        parse: Some(|input| {
+            let params = day_16::Params::default();
+            day_16::build_cave(input, params.minutes, &params.start_valve).map(|_| ())
+        }),
+        visualize: None,
+        generate: Some(|rng, size| Ok(day_16::generate(rng, size))),
+        stream: None,
+        part1: |input, o| {
+            answer(
+                input,
+                day_16::part1_with(input, &params(o, day_16::Params::set)?),
+            )
+        },
+        part2: |input, o| {
+            answer(
+                input,
+                day_16::part2_with(input, &params(o, day_16::Params::set)?),
+            )
+        },
+    },
+];
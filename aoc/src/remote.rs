@@ -0,0 +1,189 @@
+use std::{env, fmt, fs, time::Duration};
+
+use ureq::{Agent, AgentBuilder};
+
+use crate::cache::{self, Cache, Submission};
+
+pub const DEFAULT_SERVER: &str = "https://adventofcode.com";
+pub const SESSION_FILE: &str = ".aoc-session";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+pub fn parse_verdict(page: &str) -> Result<Verdict, String> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("too high") {
+            Ok(Verdict::TooHigh)
+        } else if page.contains("too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Err(String::from(
+            "Answer was submitted too recently, wait before trying again",
+        ))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err(String::from(
+            "This part is locked or has already been solved",
+        ))
+    } else {
+        Err(String::from("Unrecognized response from the server"))
+    }
+}
+
+pub fn session_from_env() -> Option<String> {
+    env::var("AOC_SESSION")
+        .or_else(|_| fs::read_to_string(SESSION_FILE))
+        .map(|session| session.trim().to_string())
+        .ok()
+}
+
+pub fn server_from_env() -> String {
+    env::var("AOC_SERVER").unwrap_or_else(|_| String::from(DEFAULT_SERVER))
+}
+
+pub struct Client {
+    server: String,
+    session: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(server: &str, session: Option<String>) -> Self {
+        Self {
+            server: server.trim_end_matches('/').to_string(),
+            session,
+            agent: AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent("aoc-runner")
+                .build(),
+        }
+    }
+
+    fn url(&self, year: u16, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.server, year, day, path)
+    }
+
+    fn cookie(&self) -> Result<String, String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(format!(
+                "No session cookie found, set AOC_SESSION or write it to {}",
+                SESSION_FILE
+            )),
+        }
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        self.agent
+            .get(&url)
+            .set("Cookie", &self.cookie()?)
+            .call()
+            .map_err(|e| format!("Unable to fetch {}: {}", url, e))?
+            .into_string()
+            .map_err(|e| format!("Unable to read response from {}: {}", url, e))
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let url = self.url(year, day, "/answer");
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| format!("Unable to submit to {}: {}", url, e))?
+            .into_string()
+            .map_err(|e| format!("Unable to read response from {}: {}", url, e))?;
+        parse_verdict(&page)
+    }
+}
+
+pub fn fetch(
+    client: &Client,
+    cache: &Cache,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<bool, String> {
+    if !force && cache.read_input(year, day).is_some() {
+        return Ok(false);
+    }
+    let input = client.fetch_input(year, day)?;
+    cache.store_input(year, day, &input)?;
+    Ok(true)
+}
+
+pub enum Submitted {
+    Known(Verdict),
+    New(Verdict),
+}
+
+pub fn submit(
+    client: &Client,
+    cache: &Cache,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submitted, String> {
+    if answer.trim().is_empty() || answer.contains('\n') {
+        return Err(format!("Answer '{}' cannot be submitted", answer));
+    }
+    let history = cache.submissions(year, day)?;
+    if let Some(verdict) = cache::known_verdict(&history, part, answer) {
+        return Ok(Submitted::Known(verdict));
+    }
+
+    let verdict = client.submit(year, day, part, answer)?;
+    cache.record(
+        year,
+        day,
+        &Submission {
+            part,
+            verdict,
+            answer: answer.to_string(),
+        },
+    )?;
+    Ok(Submitted::New(verdict))
+}
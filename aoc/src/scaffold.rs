@@ -0,0 +1,255 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const README_TEMPLATE: &str = include_str!("../templates/README.md.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const REGISTRY_TEMPLATE: &str = include_str!("../templates/registry.rs.tmpl");
+const YEARS_START: &str = "pub const YEARS: &[(u16, &[Solution])] = &[";
+
+// The 2022 crates predate multi-year support and keep their short names.
+const LEGACY_YEAR: u16 = 2022;
+
+pub fn crate_name(year: u16, day: u8) -> String {
+    if year == LEGACY_YEAR {
+        format!("day_{}", day)
+    } else {
+        format!("day_{}_{}", year, day)
+    }
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{crate}}", &crate_name(year, day))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Unable to read file {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Unable to create directory {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("Unable to write file {}: {}", path.display(), e))
+}
+
+fn add_workspace_member(manifest: &str, year: u16) -> Result<Option<String>, String> {
+    let member = format!("\"{}/day_*\"", year);
+    if manifest.contains(&member) {
+        return Ok(None);
+    }
+    let start = manifest
+        .find("members = [")
+        .ok_or("Workspace manifest has no members list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Workspace members list is not closed")?;
+
+    Ok(Some(format!(
+        "{}, {}{}",
+        &manifest[..end],
+        member,
+        &manifest[end..]
+    )))
+}
+
+fn add_dependency(manifest: &str, year: u16, day: u8) -> String {
+    let dependency = format!(
+        "{} = {{ path = \"../{}/day_{}\" }}",
+        crate_name(year, day),
+        year,
+        day
+    );
+    let mut lines = manifest.lines().collect::<Vec<&str>>();
+    let position = lines
+        .iter()
+        .rposition(|line| line.starts_with("day_"))
+        .or_else(|| lines.iter().position(|line| *line == "[dependencies]"))
+        .map_or(lines.len(), |i| i + 1);
+    lines.insert(position, &dependency);
+    lines.join("\n") + "\n"
+}
+
+fn year_module(year: u16) -> String {
+    format!("y{}", year)
+}
+
+// Declares the new year's registry module and adds it to YEARS, both in year order
+fn add_year(registry: &str, year: u16) -> Result<String, String> {
+    let module = format!("mod {};", year_module(year));
+    let mut lines = registry.lines().map(String::from).collect::<Vec<String>>();
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("mod y"))
+        .map(|(i, line)| (i, line.clone()))
+        .collect::<Vec<(usize, String)>>();
+    let position = match modules.iter().find(|(_, line)| *line > module) {
+        Some((i, _)) => *i,
+        None => modules.last().ok_or("Registry has no year modules")?.0 + 1,
+    };
+    lines.insert(position, module);
+    let registry = lines.join("\n") + "\n";
+
+    let start = registry
+        .find(YEARS_START)
+        .ok_or("Registry has no YEARS list")?;
+    let end = start
+        + registry[start..]
+            .find("];")
+            .ok_or("YEARS list is not closed")?
+        + 2;
+    let mut years = registry[start + YEARS_START.len()..end - 2]
+        .split('(')
+        .filter_map(|entry| entry.split(',').next()?.trim().parse::<u16>().ok())
+        .collect::<Vec<u16>>();
+    years.push(year);
+    years.sort();
+
+    let entries = years
+        .iter()
+        .map(|y| format!("({}, {}::SOLUTIONS)", y, year_module(*y)))
+        .collect::<Vec<String>>();
+    let single_line = format!("{}{}];", YEARS_START, entries.join(", "));
+    let list = if single_line.len() <= 100 {
+        single_line
+    } else {
+        format!("{}\n    {},\n];", YEARS_START, entries.join(",\n    "))
+    };
+
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        list,
+        &registry[end..]
+    ))
+}
+
+fn registered_entries(registry: &str) -> Vec<(usize, u16, u8)> {
+    let mut entries = vec![];
+    let mut offset = 0;
+
+    while let Some(found) = registry[offset..].find("    Solution {\n") {
+        let start = offset + found;
+        let mut fields = registry[start..].lines().skip(1).take(2).map(|line| {
+            line.trim()
+                .split_once(": ")
+                .map(|(_, value)| value.trim_end_matches(','))
+                .unwrap_or_default()
+        });
+        if let (Some(Ok(year)), Some(Ok(day))) = (
+            fields.next().map(str::parse::<u16>),
+            fields.next().map(str::parse::<u8>),
+        ) {
+            entries.push((start, year, day));
+        }
+        offset = start + 1;
+    }
+    entries
+}
+
+fn register(registry: &str, year: u16, day: u8) -> Result<String, String> {
+    let entries = registered_entries(registry);
+    if entries.iter().any(|(_, y, d)| (*y, *d) == (year, day)) {
+        return Err(format!("{} day {} is already registered", year, day));
+    }
+
+    let position = match entries.iter().find(|(_, y, d)| (*y, *d) > (year, day)) {
+        Some((start, _, _)) => *start,
+        None => {
+            let solutions = registry
+                .find("pub const SOLUTIONS")
+                .ok_or("Registry has no SOLUTIONS list")?;
+            solutions
+                + registry[solutions..]
+                    .find("\n];")
+                    .ok_or("SOLUTIONS list is not closed")?
+                + 1
+        }
+    };
+    let name = crate_name(year, day);
+    let entry = format!(
+        "    Solution {{\n        year: {year},\n        day: {day},\n        parse: Some(|input| {name}::parse_input(input).map(|_| ())),\n        visualize: None,\n        generate: None,\n        stream: None,\n        part1: |input, o| no_params(o).and_then(|_| answer(input, {name}::part1(input))),\n        part2: |input, o| no_params(o).and_then(|_| answer(input, {name}::part2(input))),\n    }},\n"
+    );
+
+    Ok(format!(
+        "{}{}{}",
+        &registry[..position],
+        entry,
+        &registry[position..]
+    ))
+}
+
+pub fn create_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{}', expected 1 to 25", day));
+    }
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry_dir = root.join("aoc").join("src").join("registry");
+    let registry_path = registry_dir.join("mod.rs");
+    let year_path = registry_dir.join(format!("{}.rs", year_module(year)));
+    if !registry_path.exists() {
+        return Err(format!(
+            "No runner found in {}, run this from the repository root",
+            root.display()
+        ));
+    }
+    let dir = root.join(year.to_string()).join(format!("day_{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let (year_registry, registry) = if year_path.exists() {
+        (register(&read(&year_path)?, year, day)?, None)
+    } else {
+        (
+            register(REGISTRY_TEMPLATE, year, day)?,
+            Some(add_year(&read(&registry_path)?, year)?),
+        )
+    };
+    let workspace = add_workspace_member(&read(&workspace_manifest)?, year)?;
+    let runner = add_dependency(&read(&runner_manifest)?, year, day);
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, year, day)),
+        (dir.join("README.md"), render(README_TEMPLATE, year, day)),
+        (
+            dir.join("src").join("lib.rs"),
+            render(LIB_TEMPLATE, year, day),
+        ),
+        (
+            dir.join("src").join("main.rs"),
+            render(MAIN_TEMPLATE, year, day),
+        ),
+        (dir.join("input_p1"), String::new()),
+        (dir.join("test_input_p1"), String::new()),
+    ];
+    let mut touched = vec![];
+    for (path, content) in files {
+        write(&path, &content)?;
+        touched.push(path);
+    }
+
+    write(&year_path, &year_registry)?;
+    touched.push(year_path);
+    if let Some(registry) = registry {
+        write(&registry_path, &registry)?;
+        touched.push(registry_path);
+    }
+    write(&runner_manifest, &runner)?;
+    touched.push(runner_manifest);
+    if let Some(workspace) = workspace {
+        write(&workspace_manifest, &workspace)?;
+        touched.push(workspace_manifest);
+    }
+    Ok(touched)
+}
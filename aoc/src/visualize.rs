@@ -0,0 +1,214 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use aoc_common::Grid;
+
+use crate::registry::Visualizer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    Text,
+    Pbm,
+    Ppm,
+}
+
+impl FrameFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "text" | "txt" => Ok(FrameFormat::Text),
+            "pbm" => Ok(FrameFormat::Pbm),
+            "ppm" => Ok(FrameFormat::Ppm),
+            _ => Err(format!(
+                "Invalid frame format '{}', expected text, pbm or ppm",
+                value
+            )),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FrameFormat::Text => "txt",
+            FrameFormat::Pbm => "pbm",
+            FrameFormat::Ppm => "ppm",
+        }
+    }
+}
+
+fn is_background(c: char) -> bool {
+    matches!(c, '.' | ' ')
+}
+
+pub fn colour(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => [250, 250, 245],
+        '#' => [40, 40, 40],
+        'o' => [230, 190, 60],
+        '+' | '@' | 'H' => [220, 50, 50],
+        '*' => [50, 120, 220],
+        'a'..='z' => {
+            let shade = 80 + (c as u8 - b'a') * 6;
+            [shade / 2, shade, shade / 2]
+        }
+        _ => {
+            let hash = (c as u32).wrapping_mul(2654435761);
+            [(hash >> 24) as u8, (hash >> 16) as u8, (hash >> 8) as u8]
+        }
+    }
+}
+
+fn scaled_rows(frame: &Grid<char>, scale: usize) -> impl Iterator<Item = &[char]> {
+    frame
+        .rows()
+        .flat_map(move |row| std::iter::repeat_n(row, scale))
+}
+
+// Plain (P1) bitmap: everything that is not background is black
+pub fn to_pbm(frame: &Grid<char>, scale: usize) -> Vec<u8> {
+    let mut out = format!("P1\n{} {}\n", frame.width() * scale, frame.height() * scale);
+    for row in scaled_rows(frame, scale) {
+        let line = row
+            .iter()
+            .flat_map(|&c| std::iter::repeat_n(if is_background(c) { "0" } else { "1" }, scale))
+            .collect::<Vec<&str>>()
+            .join(" ");
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out.into_bytes()
+}
+
+// Binary (P6) pixmap coloured by `colour`
+pub fn to_ppm(frame: &Grid<char>, scale: usize) -> Vec<u8> {
+    let mut out = format!(
+        "P6\n{} {}\n255\n",
+        frame.width() * scale,
+        frame.height() * scale
+    )
+    .into_bytes();
+    for row in scaled_rows(frame, scale) {
+        for &c in row {
+            for _ in 0..scale {
+                out.extend_from_slice(&colour(c));
+            }
+        }
+    }
+    out
+}
+
+pub trait FrameSink {
+    fn write_frame(&mut self, frame: &Grid<char>) -> Result<(), String>;
+}
+
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Self {
+            out,
+            delay: Duration::from_secs_f64(1.0 / fps as f64),
+        }
+    }
+
+    pub fn start(&mut self) -> Result<(), String> {
+        // Clear the screen and hide the cursor
+        write!(self.out, "\x1b[2J\x1b[?25l").map_err(|e| e.to_string())
+    }
+
+    pub fn finish(&mut self) -> Result<(), String> {
+        write!(self.out, "\x1b[?25h").map_err(|e| e.to_string())?;
+        self.out.flush().map_err(|e| e.to_string())
+    }
+}
+
+impl<W: Write> FrameSink for Terminal<W> {
+    fn write_frame(&mut self, frame: &Grid<char>) -> Result<(), String> {
+        writeln!(self.out, "\x1b[H{}", frame).map_err(|e| e.to_string())?;
+        self.out.flush().map_err(|e| e.to_string())?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+pub struct Export {
+    dir: PathBuf,
+    format: FrameFormat,
+    scale: usize,
+    pub written: usize,
+}
+
+impl Export {
+    pub fn new(dir: &Path, format: FrameFormat, scale: usize) -> Result<Self, String> {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Unable to create directory {}: {}", dir.display(), e))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            format,
+            scale,
+            written: 0,
+        })
+    }
+
+    pub fn frame_path(&self, index: usize) -> PathBuf {
+        self.dir
+            .join(format!("frame_{:05}.{}", index, self.format.extension()))
+    }
+}
+
+impl FrameSink for Export {
+    fn write_frame(&mut self, frame: &Grid<char>) -> Result<(), String> {
+        let content = match self.format {
+            FrameFormat::Text => format!("{}\n", frame).into_bytes(),
+            FrameFormat::Pbm => to_pbm(frame, self.scale),
+            FrameFormat::Ppm => to_ppm(frame, self.scale),
+        };
+        let path = self.frame_path(self.written);
+        fs::write(&path, content)
+            .map_err(|e| format!("Unable to write file {}: {}", path.display(), e))?;
+        self.written += 1;
+        Ok(())
+    }
+}
+
+// Passes every n-th frame to the sink, always including the final state
+pub fn play(
+    visualize: Visualizer,
+    input: &str,
+    every: usize,
+    sink: &mut dyn FrameSink,
+) -> Result<usize, String> {
+    let mut count = 0;
+    let mut skipped = None;
+    let mut error = None;
+
+    visualize(input, &mut |frame| {
+        if error.is_some() {
+            return;
+        }
+        if count % every == 0 {
+            skipped = None;
+            if let Err(e) = sink.write_frame(frame) {
+                error = Some(e);
+            }
+        } else {
+            skipped = Some(frame.clone());
+        }
+        count += 1;
+    })
+    .map_err(|e| e.render(input))?;
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+    if let Some(frame) = skipped {
+        sink.write_frame(&frame)?;
+    }
+    Ok(count)
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use aoc::bench::{self, DayTiming};
+
+fn timings() -> Vec<DayTiming> {
+    vec![
+        DayTiming {
+            year: 2022,
+            day: 1,
+            parse: Some(Duration::from_micros(250)),
+            part1: Duration::from_millis(1),
+            part2: Duration::from_micros(1500),
+        },
+        DayTiming {
+            year: 2022,
+            day: 9,
+            parse: None,
+            part1: Duration::from_millis(12),
+            part2: Duration::from_millis(20),
+        },
+    ]
+}
+
+#[test]
+fn markdown_table_has_one_row_per_day() {
+    let table = bench::to_markdown(&timings());
+    let rows = table.lines().collect::<Vec<&str>>();
+
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[2], "| 2022 | 1 | 0.250 | 1.000 | 1.500 |");
+    assert_eq!(rows[3], "| 2022 | 9 | - | 12.000 | 20.000 |");
+}
+
+#[test]
+fn json_reports_missing_parse_step_as_null() {
+    let json = bench::to_json(&timings(), 7);
+
+    assert!(json.contains("\"runs\": 7"));
+    assert!(json.contains("\"day\": 1, \"parse_ms\": 0.250000"));
+    assert!(json.contains("\"day\": 9, \"parse_ms\": null"));
+}
+
+#[test]
+fn every_day_times_its_parse_step() {
+    for solution in aoc::registry::all() {
+        let Some(parse) = solution.parse else {
+            panic!("{} day {} has no parse step", solution.year, solution.day);
+        };
+        let example = solution.day_dir().join("test_input_p1");
+        let input = std::fs::read_to_string(std::path::Path::new("..").join(example)).unwrap();
+        assert_eq!(
+            parse(&input),
+            Ok(()),
+            "{} day {}",
+            solution.year,
+            solution.day
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use aoc::{
+    answers::KnownAnswers,
+    check::{self, DayInput, Status},
+    registry::{self, Solution},
+};
+use aoc_common::Answer;
+
+const CALORIES: &str = include_str!("../../2022/day_1/test_input_p1");
+const SIGNALS: &str = include_str!("../../2022/day_10/test_input_p1");
+
+fn days() -> Vec<DayInput> {
+    vec![
+        DayInput {
+            solution: registry::find(2022, 10).unwrap(),
+            input: Ok(SIGNALS.to_string()),
+            known: None,
+        },
+        DayInput {
+            solution: registry::find(2022, 1).unwrap(),
+            input: Ok(CALORIES.to_string()),
+            known: Some(KnownAnswers {
+                part1: Some(String::from("24000")),
+                part2: Some(String::from("1")),
+            }),
+        },
+        DayInput {
+            solution: registry::find(2022, 2).unwrap(),
+            input: Err(String::from("No input for 2022 day 2")),
+            known: None,
+        },
+    ]
+}
+
+#[test]
+fn parallel_results_are_sorted_and_checked() {
+    let results = check::run_parallel(&days(), &[1, 2], 4);
+    let summary = results
+        .iter()
+        .map(|r| (r.day, r.part, r.status()))
+        .collect::<Vec<(u8, u8, Status)>>();
+
+    assert_eq!(
+        summary,
+        vec![
+            (1, 1, Status::Correct),
+            (1, 2, Status::Wrong),
+            (2, 1, Status::Failed),
+            (2, 2, Status::Failed),
+            (10, 1, Status::Unchecked),
+            (10, 2, Status::Unchecked),
+        ]
+    );
+}
+
+#[test]
+fn table_and_summary() {
+    let results = check::run_parallel(&days(), &[2], 1);
+    let table = check::to_table(&results);
+    let rows = table.lines().collect::<Vec<&str>>();
+
+    assert_eq!(rows.len(), 5);
+    assert!(rows[2].starts_with("|   1 |    2 | 45000 "));
+    assert!(rows[2].contains(" ✗ "));
+    assert!(rows[3].contains("error: No input for 2022 day 2"));
+    assert!(rows[4].contains("40x6 grid"));
+    assert!(rows[4].contains(" - "));
+
+    let summary = check::summary(&results, Duration::from_millis(5), 1);
+    assert_eq!(
+        summary.lines().collect::<Vec<&str>>(),
+        vec![
+            "3 parts in 5.000 ms on 1 thread: 0 correct, 1 wrong, 1 failed, 1 unchecked",
+            "2022 day 1 part 2: expected 1, got 45000",
+            "2022 day 2 part 2: No input for 2022 day 2",
+        ]
+    );
+}
+
+static PANICKING: Solution = Solution {
+    year: 2022,
+    day: 99,
+    parse: None,
+    visualize: None,
+    generate: None,
+    stream: None,
+    part1: |_, _| panic!("no solution found"),
+    part2: |input, _| Ok(Answer::Integer(input.len() as i64)),
+};
+
+#[test]
+fn a_panicking_solver_only_fails_its_part() {
+    let mut days = days();
+    days.push(DayInput {
+        solution: &PANICKING,
+        input: Ok(String::from("abc")),
+        known: None,
+    });
+    let results = check::run_parallel(&days, &[1, 2], 3);
+
+    assert_eq!(results.len(), 8);
+    let panicked = results.iter().find(|r| r.day == 99 && r.part == 1).unwrap();
+    assert_eq!(panicked.status(), Status::Failed);
+    assert_eq!(
+        panicked.answer,
+        Err(String::from("panicked: no solution found"))
+    );
+    let other = results.iter().find(|r| r.day == 99 && r.part == 2).unwrap();
+    assert_eq!(other.answer, Ok(Answer::Integer(3)));
+}
+
+#[test]
+fn never_more_workers_than_jobs() {
+    assert_eq!(check::worker_count(6, 16), 6);
+    assert_eq!(check::worker_count(6, 4), 4);
+    assert_eq!(check::worker_count(0, 4), 1);
+    assert_eq!(check::worker_count(3, 0), 1);
+}
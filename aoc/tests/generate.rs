@@ -0,0 +1,33 @@
+use aoc::registry;
+use aoc_common::Rng;
+
+#[test]
+fn every_day_generates_solvable_input() {
+    for solution in registry::for_year(2022) {
+        let generate = solution.generate.expect("generator should be registered");
+        let input = generate(&mut Rng::new(7), 12).unwrap();
+        let overrides = match solution.day {
+            15 => vec![
+                (String::from("row"), String::from("6")),
+                (String::from("search_bound"), String::from("12")),
+            ],
+            _ => vec![],
+        };
+        for part in [1, 2] {
+            assert!(
+                solution.solve(part, &input, &overrides).is_ok(),
+                "day {} part {}",
+                solution.day,
+                part
+            );
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    let generate = registry::find(2022, 16).unwrap().generate.unwrap();
+    let input = generate(&mut Rng::new(3), 20).unwrap();
+    assert_eq!(generate(&mut Rng::new(3), 20).unwrap(), input);
+    assert_ne!(generate(&mut Rng::new(4), 20).unwrap(), input);
+}
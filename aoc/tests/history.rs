@@ -0,0 +1,146 @@
+use std::{env, fs, path::PathBuf, process};
+
+use aoc::{
+    history::{self, Change},
+    output::Record,
+};
+use aoc_common::Answer;
+
+fn temp_log(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-history-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join(history::FILE_NAME)
+}
+
+fn answer(input: &str, day: u8, part: u8, answer: Answer) -> (String, Record) {
+    (
+        history::input_hash(input),
+        Record {
+            year: 2022,
+            day,
+            part,
+            answer,
+        },
+    )
+}
+
+#[test]
+fn input_hash_is_stable() {
+    assert_eq!(history::input_hash(""), "cbf29ce484222325");
+    assert_eq!(history::input_hash("a"), "af63dc4c8601ec8c");
+    assert_ne!(history::input_hash("1\n2\n"), history::input_hash("1\n2"));
+}
+
+#[test]
+fn runs_are_appended_and_read_back() {
+    let log = temp_log("roundtrip");
+    let grid = Answer::grid(["#.\\", ".#\t"]);
+
+    assert_eq!(
+        history::append(&log, "abc1234", &[answer("x", 1, 1, Answer::Integer(7))]),
+        Ok(1)
+    );
+    assert_eq!(
+        history::append(&log, "def5678+", &[answer("x", 10, 2, grid.clone())]),
+        Ok(2)
+    );
+    // Nothing to record, nothing written
+    assert_eq!(history::append(&log, "def5678+", &[]), Ok(3));
+
+    let entries = history::load(&log).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].run, 2);
+    assert_eq!(entries[1].commit, "def5678+");
+    assert_eq!(entries[1].answer, grid.to_string());
+    assert_eq!(history::runs(&entries), vec![1, 2]);
+    assert_eq!(history::resolve_run(&entries, "abc"), Ok(1));
+    assert_eq!(history::resolve_run(&entries, "2"), Ok(2));
+    assert!(history::resolve_run(&entries, "9").is_err());
+    assert!(history::resolve_run(&entries, "fff").is_err());
+
+    fs::write(&log, "1\tnot a time\t-\t2022\t1\t1\tabc\t7\n").unwrap();
+    assert!(history::load(&log).is_err());
+
+    fs::remove_dir_all(log.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn diff_reports_changed_and_new_answers() {
+    let log = temp_log("diff");
+    history::append(
+        &log,
+        "aaa",
+        &[
+            answer("input", 1, 1, Answer::Integer(68787)),
+            answer("input", 1, 2, Answer::Integer(198041)),
+            answer("input", 14, 2, Answer::Integer(23925)),
+        ],
+    )
+    .unwrap();
+    // A partial run only re-records some of the answers
+    history::append(
+        &log,
+        "bbb",
+        &[answer("input", 1, 1, Answer::Integer(68787))],
+    )
+    .unwrap();
+    history::append(
+        &log,
+        "ccc",
+        &[
+            answer("input", 14, 2, Answer::Integer(23926)),
+            answer("other input", 1, 1, Answer::Integer(5)),
+        ],
+    )
+    .unwrap();
+    let entries = history::load(&log).unwrap();
+
+    assert_eq!(history::diff(&entries, 1, 2), vec![]);
+    let changes = history::diff(&entries, 1, 3);
+    assert_eq!(
+        changes,
+        vec![
+            Change {
+                year: 2022,
+                day: 1,
+                part: 1,
+                input_hash: history::input_hash("other input"),
+                before: None,
+                after: String::from("5"),
+            },
+            Change {
+                year: 2022,
+                day: 14,
+                part: 2,
+                input_hash: history::input_hash("input"),
+                before: Some(String::from("23925")),
+                after: String::from("23926"),
+            },
+        ]
+    );
+    assert_eq!(
+        history::render(&changes),
+        format!(
+            "2022 day 1 part 1: 5 (first answer for input {})\n2022 day 14 part 2: 23925 -> 23926",
+            &history::input_hash("other input")[..8]
+        )
+    );
+
+    fs::remove_dir_all(log.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn streamed_input_hashes_like_a_read_one() {
+    let input = "1000\n2000\n\n3000\n".repeat(1000);
+    let mut reader =
+        history::HashingReader::new(std::io::BufReader::with_capacity(7, input.as_bytes()));
+
+    // A solver that reads by lines, by whole buffers, and then stops early
+    let mut line = String::new();
+    std::io::BufRead::read_line(&mut reader, &mut line).unwrap();
+    let mut buf = [0; 5];
+    std::io::Read::read(&mut reader, &mut buf).unwrap();
+    assert_eq!(line, "1000\n");
+
+    assert_eq!(reader.finish().unwrap(), history::input_hash(&input));
+}
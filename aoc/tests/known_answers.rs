@@ -0,0 +1,38 @@
+use std::{fs, path::Path};
+
+use aoc::{answers, registry};
+
+#[test]
+fn real_inputs_match_known_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut failures = vec![];
+
+    for solution in registry::all() {
+        let dir = root.join(solution.day_dir());
+        let Some(known) = answers::load(&dir.join(answers::FILE_NAME)).unwrap() else {
+            continue;
+        };
+        let Ok(input) = fs::read_to_string(dir.join("input_p1")) else {
+            continue;
+        };
+
+        for part in [1, 2] {
+            let Some(expected) = known.get(part) else {
+                continue;
+            };
+            match solution.solve(part, &input, &[]) {
+                Ok(actual) if actual.to_string() == expected => (),
+                Ok(actual) => failures.push(format!(
+                    "{} day {} part {}: expected {}, got {}",
+                    solution.year, solution.day, part, expected, actual
+                )),
+                Err(e) => failures.push(format!(
+                    "{} day {} part {}: {}",
+                    solution.year, solution.day, part, e
+                )),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,100 @@
+use std::{env, fs, path::PathBuf, process, time::Duration};
+
+use aoc::{bench::DayTiming, cache::Cache, list, registry};
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-list-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+    root
+}
+
+fn write(path: PathBuf, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn every_registered_part_in_the_repo_is_listed() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let cache = Cache::new(&temp_root("repo"));
+    let statuses = list::collect(&root, &cache, Some(2022)).unwrap();
+
+    assert_eq!(statuses.len(), registry::for_year(2022).count() * 2);
+    assert!(statuses
+        .iter()
+        .all(|s| s.implemented && s.tested && s.benchmarked.is_none()));
+    assert!(list::years(&root).contains(&2022));
+}
+
+#[test]
+fn status_comes_from_tests_answers_and_bench_results() {
+    let root = temp_root("status");
+    let cache = Cache::new(&root.join("cache"));
+    // Only tests named after a part count, not calls from helpers or other tests
+    write(
+        root.join("2022/day_1/tests/examples.rs"),
+        "#[test]\nfn part1_example() {}\n\n\
+         #[test]\nfn top_elves() {\n    day_1::part2(EXAMPLE);\n}\n\n\
+         // #[test]\nfn part2_example() {}\n",
+    );
+    write(root.join("2022/day_1/answers.toml"), "part2 = 198041\n");
+    write(
+        root.join("2022/day_2/tests/examples.rs"),
+        "#[test]\n#[should_panic]\nfn part2_needs_rules() {}\n",
+    );
+    // Not registered, e.g. a year that has not been wired into the runner yet
+    write(root.join("2019/day_4/Cargo.toml"), "");
+    cache
+        .store_timing(&DayTiming {
+            year: 2022,
+            day: 1,
+            parse: None,
+            part1: Duration::from_micros(1500),
+            part2: Duration::from_micros(250),
+        })
+        .unwrap();
+
+    assert_eq!(list::years(&root), vec![2019, 2022]);
+    let statuses = list::collect(&root, &cache, None).unwrap();
+    let row = |year, day, part| {
+        let s = statuses
+            .iter()
+            .find(|s| (s.year, s.day, s.part) == (year, day, part))
+            .unwrap();
+        (s.implemented, s.tested, s.known, s.benchmarked)
+    };
+    assert_eq!(
+        row(2022, 1, 1),
+        (true, true, false, Some(Duration::from_micros(1500)))
+    );
+    assert_eq!(
+        row(2022, 1, 2),
+        (true, false, true, Some(Duration::from_micros(250)))
+    );
+    assert_eq!(row(2022, 2, 2), (true, true, false, None));
+    assert_eq!(row(2019, 4, 1), (false, false, false, None));
+
+    let table = list::to_table(&statuses);
+    assert!(
+        table.contains("| 2022 |   1 |    1 |      ✓      |   ✓    |      -       |      1.500 |")
+    );
+    let summary = list::summary(&statuses);
+    assert!(summary.starts_with("2019: 0 of 2 parts implemented, 0 tested"));
+    assert!(summary.contains(
+        "2022: 32 of 32 parts implemented, 2 tested, 1 with a known answer, 2 benchmarked"
+    ));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn corrupt_bench_results_are_reported() {
+    let root = temp_root("corrupt");
+    let cache = Cache::new(&root);
+    write(root.join("2022/day_3/bench"), "part1\tfast\n");
+    assert!(cache.timing(2022, 3).is_err());
+    assert!(cache.timing(2022, 4).unwrap().is_none());
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,55 @@
+use aoc::output::{self, Format, Record};
+use aoc_common::Answer;
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: Answer::from("CMZ"),
+        },
+        Record {
+            year: 2022,
+            day: 10,
+            part: 1,
+            answer: Answer::from(13140),
+        },
+        Record {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Answer::grid(["#.", ".#"]),
+        },
+    ]
+}
+
+#[test]
+fn text_groups_parts_by_day() {
+    assert_eq!(
+        output::render(Format::Text, &records()),
+        "--- 2022 Day 5 ---\nPart 1: CMZ\n--- 2022 Day 10 ---\nPart 1: 13140\nPart 2:\n#.\n.#"
+    );
+}
+
+#[test]
+fn json_keeps_answer_types() {
+    let json = output::render(Format::Json, &records());
+
+    assert!(json.contains("\"day\": 5, \"part\": 1, \"kind\": \"string\", \"answer\": \"CMZ\""));
+    assert!(json.contains("\"kind\": \"integer\", \"answer\": 13140"));
+    assert!(json.contains("\"kind\": \"grid\", \"answer\": [\"#.\", \".#\"]"));
+}
+
+#[test]
+fn csv_quotes_multi_line_answers() {
+    assert_eq!(
+        output::render(Format::Csv, &records()),
+        "year,day,part,kind,answer\n2022,5,1,string,CMZ\n2022,10,1,integer,13140\n2022,10,2,grid,\"#.\n.#\""
+    );
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    assert!(Format::parse("xml").is_err());
+}
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use aoc::{params, registry};
+use aoc_common::Answer;
+
+#[test]
+fn day_2_plays_the_configured_rules() {
+    let overrides = params::load_file(Path::new("../2022/day_2/rpsls_params.toml")).unwrap();
+    let solution = registry::find(2022, 2).unwrap();
+    let input = "A Y\nE X\nC Z\nD X\n";
+    assert_eq!(
+        solution.solve(1, input, &overrides),
+        Ok(Answer::Integer(27))
+    );
+    assert_eq!(
+        solution.solve(2, input, &overrides),
+        Ok(Answer::Integer(23))
+    );
+
+    let stream = solution.stream.unwrap();
+    assert_eq!(
+        stream(&mut input.as_bytes(), &overrides),
+        Ok([Answer::Integer(27), Answer::Integer(23)])
+    );
+
+    let broken = [(String::from("points"), String::from("1 2"))];
+    assert!(solution.solve(1, input, &broken).is_err());
+}
+
+#[test]
+fn day_2_optimizes_under_the_configured_rules() {
+    let mut overrides = params::load_file(Path::new("../2022/day_2/rpsls_params.toml")).unwrap();
+    overrides.push((String::from("optimize"), String::from("true")));
+    let solution = registry::find(2022, 2).unwrap();
+    let input = "A Y\nE X\nC Z\nD X\n";
+
+    // Five response letters over five items, three outcome letters over three outcomes
+    let Ok(Answer::Grid(items)) = solution.solve(1, input, &overrides) else {
+        panic!("expected a report for part 1");
+    };
+    assert_eq!(items[0], "Second column as items:");
+    assert_eq!(items.iter().filter(|l| l.starts_with("  ")).count(), 120);
+    assert!(items.iter().any(|l| l.ends_with("27  (part 1)")));
+
+    let Ok(Answer::Grid(outcomes)) = solution.solve(2, input, &overrides) else {
+        panic!("expected a report for part 2");
+    };
+    assert_eq!(outcomes[0], "Second column as outcomes:");
+    assert_eq!(outcomes.iter().filter(|l| l.starts_with("  ")).count(), 6);
+    assert!(outcomes.iter().any(|l| l.ends_with("23  (part 2)")));
+
+    let stream = solution.stream.unwrap();
+    assert!(stream(&mut input.as_bytes(), &overrides).is_err());
+}
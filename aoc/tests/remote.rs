@@ -0,0 +1,239 @@
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    process,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use aoc::{
+    cache::{self, Cache, Submission},
+    input,
+    registry::Solution,
+    remote::{self, Client, Submitted, Verdict},
+    scaffold,
+};
+use aoc_common::Answer;
+
+const INPUT: &str = "1000\n2000\n\n3000\n";
+const CORRECT: i64 = 3000;
+
+type RequestLog = Arc<Mutex<Vec<String>>>;
+
+fn respond(stream: TcpStream, log: &RequestLog) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+
+    let mut cookie = String::new();
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(": ").unwrap();
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = value.to_string(),
+            "content-length" => length = value.parse().unwrap(),
+            _ => (),
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    let body = String::from_utf8(body).unwrap();
+
+    let path = request_line.split_whitespace().nth(1).unwrap().to_string();
+    log.lock()
+        .unwrap()
+        .push(format!("{} {} {}", path, cookie, body));
+
+    let page = if path.ends_with("/input") {
+        String::from(INPUT)
+    } else {
+        let answer = body
+            .split('&')
+            .find_map(|field| field.strip_prefix("answer="))
+            .unwrap()
+            .parse::<i64>();
+        match answer {
+            Ok(CORRECT) => String::from("<p>That's the right answer!</p>"),
+            Ok(a) if a > CORRECT => {
+                String::from("<p>That's not the right answer; your answer is too high.</p>")
+            }
+            Ok(_) => String::from("<p>That's not the right answer; your answer is too low.</p>"),
+            Err(_) => String::from("<p>That's not the right answer.</p>"),
+        }
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        page.len(),
+        page
+    )
+    .unwrap();
+}
+
+fn mock_server() -> (String, RequestLog) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let log: RequestLog = Arc::new(Mutex::new(vec![]));
+    let server_log = Arc::clone(&log);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            respond(stream.unwrap(), &server_log);
+        }
+    });
+    (url, log)
+}
+
+fn temp_cache(name: &str) -> (PathBuf, Cache) {
+    let root = env::temp_dir().join(format!("aoc-cache-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let cache = Cache::new(&root);
+    (root, cache)
+}
+
+#[test]
+fn fetched_inputs_are_cached() {
+    let (url, log) = mock_server();
+    let (root, cache) = temp_cache("fetch");
+    let client = Client::new(&url, Some(String::from("secret")));
+
+    assert_eq!(remote::fetch(&client, &cache, 2022, 1, false), Ok(true));
+    assert_eq!(remote::fetch(&client, &cache, 2022, 1, false), Ok(false));
+    assert_eq!(cache.read_input(2022, 1).as_deref(), Some(INPUT));
+
+    let requests = log.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("/2022/day/1/input session=secret"));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn known_answers_are_not_resubmitted() {
+    let (url, log) = mock_server();
+    let (root, cache) = temp_cache("submit");
+    let client = Client::new(&url, Some(String::from("secret")));
+    let submit = |answer: &str| match remote::submit(&client, &cache, 2022, 1, 1, answer) {
+        Ok(Submitted::New(verdict)) => (verdict, false),
+        Ok(Submitted::Known(verdict)) => (verdict, true),
+        Err(e) => panic!("{}", e),
+    };
+
+    assert_eq!(submit("1000"), (Verdict::TooLow, false));
+    assert_eq!(submit("900"), (Verdict::TooLow, true));
+    assert_eq!(submit("5000"), (Verdict::TooHigh, false));
+    assert_eq!(submit("6000"), (Verdict::TooHigh, true));
+    assert_eq!(submit("abc"), (Verdict::Wrong, false));
+    assert_eq!(submit("abc"), (Verdict::Wrong, true));
+    assert_eq!(submit("3000"), (Verdict::Correct, false));
+    assert_eq!(submit("3000"), (Verdict::Correct, true));
+    assert_eq!(submit("2999"), (Verdict::Wrong, true));
+
+    assert_eq!(log.lock().unwrap().len(), 4);
+    assert!(log.lock().unwrap()[0].ends_with("level=1&answer=1000"));
+    assert_eq!(cache.submissions(2022, 1).unwrap().len(), 4);
+    assert!(cache.submissions(2022, 2).unwrap().is_empty());
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn missing_session_is_reported_before_sending() {
+    let (url, log) = mock_server();
+    let (_, cache) = temp_cache("session");
+    let client = Client::new(&url, None);
+
+    assert!(remote::fetch(&client, &cache, 2022, 1, false).is_err());
+    assert!(log.lock().unwrap().is_empty());
+}
+
+#[test]
+fn verdicts_are_read_from_the_answer_page() {
+    assert_eq!(
+        remote::parse_verdict("That's the right answer! You are one gold star closer."),
+        Ok(Verdict::Correct)
+    );
+    assert_eq!(
+        remote::parse_verdict("That's not the right answer; your answer is too high."),
+        Ok(Verdict::TooHigh)
+    );
+    assert_eq!(
+        remote::parse_verdict("That's not the right answer. If you're stuck..."),
+        Ok(Verdict::Wrong)
+    );
+    assert!(remote::parse_verdict("You gave an answer too recently").is_err());
+}
+
+#[test]
+fn bounds_only_apply_to_the_same_part() {
+    let history = vec![Submission {
+        part: 1,
+        verdict: Verdict::TooHigh,
+        answer: String::from("100"),
+    }];
+
+    assert_eq!(
+        cache::known_verdict(&history, 1, "150"),
+        Some(Verdict::TooHigh)
+    );
+    assert_eq!(cache::known_verdict(&history, 1, "99"), None);
+    assert_eq!(cache::known_verdict(&history, 2, "150"), None);
+}
+
+#[test]
+fn scaffolded_day_runs_on_fetched_input() {
+    let root = env::temp_dir().join(format!("aoc-remote-scaffold-{}", process::id()));
+    let repo = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc/src/registry")).unwrap();
+    for file in [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/registry/mod.rs",
+        "aoc/src/registry/y2022.rs",
+    ] {
+        fs::copy(repo.join(file), root.join(file)).unwrap();
+    }
+    scaffold::create_day(&root, 2022, 17).unwrap();
+
+    // Stands in for the scaffolded solver, which this build cannot register
+    let solution = Solution {
+        year: 2022,
+        day: 17,
+        parse: None,
+        visualize: None,
+        generate: None,
+        stream: None,
+        part1: |input, _| Ok(Answer::Integer(input.lines().count() as i64)),
+        part2: |input, _| Ok(Answer::Integer(input.len() as i64)),
+    };
+    let (cache_root, cache) = temp_cache("scaffold");
+    assert!(input::default_source(&root, &cache, &solution).is_err());
+
+    let (url, _) = mock_server();
+    let client = Client::new(&url, Some(String::from("secret")));
+    assert_eq!(remote::fetch(&client, &cache, 2022, 17, false), Ok(true));
+
+    // The empty input_p1 left by `aoc new` does not hide the fetched input
+    let path = input::default_source(&root, &cache, &solution).unwrap();
+    assert_eq!(path, cache.input_path(2022, 17));
+    let fetched = fs::read_to_string(path).unwrap();
+    assert_eq!(solution.solve(1, &fetched, &[]), Ok(Answer::Integer(4)));
+
+    // Once the day has its own input, that one is used
+    let local = root.join("2022/day_17/input_p1");
+    fs::write(&local, "1\n").unwrap();
+    assert_eq!(input::default_source(&root, &cache, &solution), Ok(local));
+
+    fs::remove_dir_all(root).unwrap();
+    fs::remove_dir_all(cache_root).unwrap();
+}
@@ -0,0 +1,89 @@
+use std::{env, fs, path::PathBuf, process};
+
+use aoc::scaffold;
+
+fn fake_repo(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+    let repo = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc/src/registry")).unwrap();
+    for file in [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/registry/mod.rs",
+        "aoc/src/registry/y2022.rs",
+    ] {
+        fs::copy(repo.join(file), root.join(file)).unwrap();
+    }
+    root
+}
+
+#[test]
+fn new_day_is_generated_and_registered() {
+    let root = fake_repo("register");
+
+    scaffold::create_day(&root, 2022, 17).unwrap();
+
+    let day_dir = root.join("2022/day_17");
+    for file in ["Cargo.toml", "README.md", "src/lib.rs", "src/main.rs"] {
+        assert!(day_dir.join(file).is_file(), "missing {}", file);
+    }
+    assert_eq!(fs::read_to_string(day_dir.join("input_p1")).unwrap(), "");
+    assert_eq!(
+        fs::read_to_string(day_dir.join("test_input_p1")).unwrap(),
+        ""
+    );
+    assert!(fs::read_to_string(day_dir.join("src/main.rs"))
+        .unwrap()
+        .contains("day_17::part1(input)?"));
+
+    let runner = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+    assert!(runner.contains("day_17 = { path = \"../2022/day_17\" }"));
+
+    let registry = fs::read_to_string(root.join("aoc/src/registry/y2022.rs")).unwrap();
+    let day_16 = registry.find("day: 16,").unwrap();
+    let day_17 = registry.find("day: 17,").unwrap();
+    assert!(day_16 < day_17);
+    assert!(registry.contains("answer(input, day_17::part2(input))"));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn new_year_is_added_to_the_workspace() {
+    let root = fake_repo("year");
+
+    scaffold::create_day(&root, 2023, 1).unwrap();
+
+    let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace.contains("\"2022/day_*\", \"2023/day_*\"]"));
+    let cargo = fs::read_to_string(root.join("2023/day_1/Cargo.toml")).unwrap();
+    assert!(cargo.contains("name = \"day_2023_1\""));
+
+    let years = fs::read_to_string(root.join("aoc/src/registry/mod.rs")).unwrap();
+    assert!(years.contains("mod y2022;\nmod y2023;\n"));
+    assert!(years.contains("&[(2022, y2022::SOLUTIONS), (2023, y2023::SOLUTIONS)];"));
+    let registry = fs::read_to_string(root.join("aoc/src/registry/y2023.rs")).unwrap();
+    assert!(registry.contains("year: 2023,"));
+    assert!(registry.contains("answer(input, day_2023_1::part1(input))"));
+
+    // A second day of the new year goes into the same registry
+    scaffold::create_day(&root, 2023, 2).unwrap();
+    let registry = fs::read_to_string(root.join("aoc/src/registry/y2023.rs")).unwrap();
+    assert!(registry.find("day: 1,").unwrap() < registry.find("day: 2,").unwrap());
+    let years = fs::read_to_string(root.join("aoc/src/registry/mod.rs")).unwrap();
+    assert_eq!(years.matches("mod y2023;").count(), 1);
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn existing_days_are_not_overwritten() {
+    let root = fake_repo("existing");
+
+    assert!(scaffold::create_day(&root, 2022, 1).is_err());
+    assert!(scaffold::create_day(&root, 2022, 26).is_err());
+    assert!(!root.join("2022/day_26").exists());
+
+    fs::remove_dir_all(root).unwrap();
+}
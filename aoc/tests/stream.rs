@@ -0,0 +1,52 @@
+use aoc::registry;
+use aoc_common::Answer;
+
+const CALORIES: &str = include_str!("../../2022/day_1/test_input_p1");
+const SENSORS: &str = include_str!("../../2022/day_15/test_input_p1");
+
+#[test]
+fn streamed_answers_match_solve() {
+    let solution = registry::find(2022, 1).unwrap();
+    let stream = solution.stream.unwrap();
+    assert_eq!(
+        stream(&mut CALORIES.as_bytes(), &[]),
+        Ok([
+            solution.solve(1, CALORIES, &[]).unwrap(),
+            solution.solve(2, CALORIES, &[]).unwrap()
+        ])
+    );
+}
+
+#[test]
+fn streams_take_params() {
+    let stream = registry::find(2022, 15).unwrap().stream.unwrap();
+    let overrides = [
+        (String::from("row"), String::from("10")),
+        (String::from("search_bound"), String::from("20")),
+    ];
+    assert_eq!(
+        stream(&mut SENSORS.as_bytes(), &overrides),
+        Ok([Answer::Integer(26), Answer::Integer(56000011)])
+    );
+    assert!(stream(
+        &mut SENSORS.as_bytes(),
+        &[(String::from("rows"), String::from("1"))]
+    )
+    .is_err());
+}
+
+#[test]
+fn stream_errors_carry_the_position() {
+    let stream = registry::find(2022, 1).unwrap().stream.unwrap();
+    let error = stream(&mut "100\n\nabc\n".as_bytes(), &[]).unwrap_err();
+    assert!(error.contains("line 3, column 1"), "{}", error);
+}
+
+#[test]
+fn only_line_oriented_days_stream() {
+    let streaming = registry::for_year(2022)
+        .filter(|s| s.stream.is_some())
+        .map(|s| s.day)
+        .collect::<Vec<u8>>();
+    assert_eq!(streaming, vec![1, 2, 3, 4, 6, 9, 10, 15]);
+}
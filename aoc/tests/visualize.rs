@@ -0,0 +1,79 @@
+use std::{env, fs, process};
+
+use aoc::{
+    registry,
+    visualize::{self, to_pbm, to_ppm, Export, FrameFormat, FrameSink},
+};
+use aoc_common::Grid;
+
+const SAND: &str = include_str!("../../2022/day_14/test_input_p1");
+
+struct Collect(Vec<Grid<char>>);
+
+impl FrameSink for Collect {
+    fn write_frame(&mut self, frame: &Grid<char>) -> Result<(), String> {
+        self.0.push(frame.clone());
+        Ok(())
+    }
+}
+
+fn tiny() -> Grid<char> {
+    Grid::parse("#.\n.o\n", Ok::<char, String>).unwrap()
+}
+
+#[test]
+fn bitmaps_are_scaled() {
+    assert_eq!(
+        String::from_utf8(to_pbm(&tiny(), 2)).unwrap(),
+        "P1\n4 4\n1 1 0 0\n1 1 0 0\n0 0 1 1\n0 0 1 1\n"
+    );
+
+    let ppm = to_ppm(&tiny(), 3);
+    let header = b"P6\n6 6\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 6 * 6 * 3);
+    assert_eq!(
+        &ppm[header.len()..header.len() + 3],
+        &visualize::colour('#')
+    );
+}
+
+#[test]
+fn sampling_keeps_the_final_frame() {
+    let visualizer = registry::find(2022, 14).unwrap().visualize.unwrap();
+
+    let mut all = Collect(vec![]);
+    let count = visualize::play(visualizer, SAND, 1, &mut all).unwrap();
+    // Initial cave, 24 grains, the floor appearing and the remaining 69 grains
+    assert_eq!(count, 95);
+    assert_eq!(all.0.len(), 95);
+
+    let mut sampled = Collect(vec![]);
+    visualize::play(visualizer, SAND, 10, &mut sampled).unwrap();
+    assert_eq!(sampled.0.len(), 11);
+    assert_eq!(sampled.0.last(), all.0.last());
+}
+
+#[test]
+fn frames_are_exported_numbered() {
+    let dir = env::temp_dir().join(format!("aoc-visualize-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut export = Export::new(&dir, FrameFormat::parse("txt").unwrap(), 1).unwrap();
+    export.write_frame(&tiny()).unwrap();
+    export.write_frame(&tiny()).unwrap();
+    assert_eq!(export.written, 2);
+    assert_eq!(
+        fs::read_to_string(dir.join("frame_00001.txt")).unwrap(),
+        "#.\n.o\n"
+    );
+    assert!(FrameFormat::parse("gif").is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn days_without_visualisation_are_reported() {
+    assert!(registry::find(2022, 1).unwrap().visualize.is_none());
+    assert!(registry::find(2022, 9).unwrap().visualize.is_some());
+}